@@ -0,0 +1,479 @@
+//! Arithmetic bounded to the double-int range.
+//!
+//! Overflow is defined at the double-int bounds rather than at the bounds of `i64`. Since the
+//! double-int range is symmetric, negation, absolute value, and division can never overflow.
+//!
+//! Wrapping arithmetic is performed modulo the size of the double-int range, (2^54) - 1, such that
+//! `DoubleInt::MAX.wrapping_add(1) == DoubleInt::MIN`.
+
+use crate::DoubleInt;
+
+/// Number of values in the double-int range.
+const MODULUS: i128 = DoubleInt::MAX_I128 - DoubleInt::MIN_I128 + 1;
+
+impl DoubleInt {
+    /// Converts a widened result, returning `None` if outside the double-int bounds.
+    const fn checked(val: i128) -> Option<Self> {
+        match DoubleInt::from_i128(val) {
+            Ok(val) => Some(val),
+            Err(_) => None,
+        }
+    }
+
+    /// Converts a widened result, clamping to the double-int bounds.
+    const fn saturating(val: i128) -> Self {
+        if val > DoubleInt::MAX_I128 {
+            DoubleInt::MAX
+        } else if val < DoubleInt::MIN_I128 {
+            DoubleInt::MIN
+        } else {
+            DoubleInt(val as i64)
+        }
+    }
+
+    /// Converts a widened result, wrapping around at the double-int bounds.
+    const fn wrapping(val: i128) -> Self {
+        DoubleInt(((val - DoubleInt::MIN_I128).rem_euclid(MODULUS) + DoubleInt::MIN_I128) as i64)
+    }
+
+    /// Converts a widened result, wrapping around at the double-int bounds and indicating whether
+    /// wrapping occurred.
+    const fn overflowing(val: i128) -> (Self, bool) {
+        (
+            DoubleInt::wrapping(val),
+            val > DoubleInt::MAX_I128 || val < DoubleInt::MIN_I128,
+        )
+    }
+
+    /// Checked addition. Computes `self + rhs`, returning `None` if the result is outside the
+    /// double-int bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use double_int::DoubleInt;
+    /// assert_eq!(DoubleInt::from(1).checked_add(DoubleInt::from(2)).unwrap(), 3);
+    /// assert!(DoubleInt::MAX.checked_add(DoubleInt::from(1)).is_none());
+    /// ```
+    pub const fn checked_add(self, rhs: Self) -> Option<Self> {
+        DoubleInt::checked(self.0 as i128 + rhs.0 as i128)
+    }
+
+    /// Checked subtraction. Computes `self - rhs`, returning `None` if the result is outside the
+    /// double-int bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use double_int::DoubleInt;
+    /// assert_eq!(DoubleInt::from(1).checked_sub(DoubleInt::from(2)).unwrap(), -1);
+    /// assert!(DoubleInt::MIN.checked_sub(DoubleInt::from(1)).is_none());
+    /// ```
+    pub const fn checked_sub(self, rhs: Self) -> Option<Self> {
+        DoubleInt::checked(self.0 as i128 - rhs.0 as i128)
+    }
+
+    /// Checked multiplication. Computes `self * rhs`, returning `None` if the result is outside
+    /// the double-int bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use double_int::DoubleInt;
+    /// assert_eq!(DoubleInt::from(-3).checked_mul(DoubleInt::from(4)).unwrap(), -12);
+    /// assert!(DoubleInt::MAX.checked_mul(DoubleInt::from(2)).is_none());
+    /// ```
+    pub const fn checked_mul(self, rhs: Self) -> Option<Self> {
+        DoubleInt::checked(self.0 as i128 * rhs.0 as i128)
+    }
+
+    /// Checked division. Computes `self / rhs`, returning `None` if `rhs == 0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use double_int::DoubleInt;
+    /// assert_eq!(DoubleInt::MIN.checked_div(DoubleInt::from(-1)).unwrap(), DoubleInt::MAX);
+    /// assert!(DoubleInt::from(1).checked_div(DoubleInt::from(0)).is_none());
+    /// ```
+    pub const fn checked_div(self, rhs: Self) -> Option<Self> {
+        if rhs.0 == 0 {
+            None
+        } else {
+            Some(DoubleInt(self.0 / rhs.0))
+        }
+    }
+
+    /// Checked remainder. Computes `self % rhs`, returning `None` if `rhs == 0`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use double_int::DoubleInt;
+    /// assert_eq!(DoubleInt::from(7).checked_rem(DoubleInt::from(-3)).unwrap(), 1);
+    /// assert!(DoubleInt::from(1).checked_rem(DoubleInt::from(0)).is_none());
+    /// ```
+    pub const fn checked_rem(self, rhs: Self) -> Option<Self> {
+        if rhs.0 == 0 {
+            None
+        } else {
+            Some(DoubleInt(self.0 % rhs.0))
+        }
+    }
+
+    /// Checked negation. Computes `-self`.
+    ///
+    /// Never returns `None` since the double-int range is symmetric; provided for parity with
+    /// primitive integers.
+    pub const fn checked_neg(self) -> Option<Self> {
+        Some(DoubleInt(-self.0))
+    }
+
+    /// Checked absolute value. Computes `self.abs()`.
+    ///
+    /// Never returns `None` since the double-int range is symmetric; provided for parity with
+    /// primitive integers.
+    pub const fn checked_abs(self) -> Option<Self> {
+        Some(DoubleInt(self.0.abs()))
+    }
+
+    /// Checked exponentiation. Computes `self.pow(exp)`, returning `None` if the result is outside
+    /// the double-int bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use double_int::DoubleInt;
+    /// assert_eq!(DoubleInt::from(2).checked_pow(52).unwrap(), 4_503_599_627_370_496_i64);
+    /// assert!(DoubleInt::from(2).checked_pow(53).is_none());
+    /// ```
+    pub const fn checked_pow(self, exp: u32) -> Option<Self> {
+        match (self.0 as i128).checked_pow(exp) {
+            Some(val) => DoubleInt::checked(val),
+            None => None,
+        }
+    }
+
+    /// Saturating addition. Computes `self + rhs`, clamping the result to the double-int bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use double_int::DoubleInt;
+    /// assert_eq!(DoubleInt::from(1).saturating_add(DoubleInt::from(2)), 3);
+    /// assert_eq!(DoubleInt::MAX.saturating_add(DoubleInt::from(1)), DoubleInt::MAX);
+    /// ```
+    pub const fn saturating_add(self, rhs: Self) -> Self {
+        DoubleInt::saturating(self.0 as i128 + rhs.0 as i128)
+    }
+
+    /// Saturating subtraction. Computes `self - rhs`, clamping the result to the double-int
+    /// bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use double_int::DoubleInt;
+    /// assert_eq!(DoubleInt::from(1).saturating_sub(DoubleInt::from(2)), -1);
+    /// assert_eq!(DoubleInt::MIN.saturating_sub(DoubleInt::from(1)), DoubleInt::MIN);
+    /// ```
+    pub const fn saturating_sub(self, rhs: Self) -> Self {
+        DoubleInt::saturating(self.0 as i128 - rhs.0 as i128)
+    }
+
+    /// Saturating multiplication. Computes `self * rhs`, clamping the result to the double-int
+    /// bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use double_int::DoubleInt;
+    /// assert_eq!(DoubleInt::MAX.saturating_mul(DoubleInt::from(2)), DoubleInt::MAX);
+    /// assert_eq!(DoubleInt::MAX.saturating_mul(DoubleInt::from(-2)), DoubleInt::MIN);
+    /// ```
+    pub const fn saturating_mul(self, rhs: Self) -> Self {
+        DoubleInt::saturating(self.0 as i128 * rhs.0 as i128)
+    }
+
+    /// Saturating division. Computes `self / rhs`.
+    ///
+    /// Division can never leave the double-int bounds; provided for parity with primitive
+    /// integers.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rhs` is zero.
+    pub const fn saturating_div(self, rhs: Self) -> Self {
+        DoubleInt(self.0 / rhs.0)
+    }
+
+    /// Saturating negation. Computes `-self`.
+    ///
+    /// Negation can never leave the double-int bounds; provided for parity with primitive
+    /// integers.
+    pub const fn saturating_neg(self) -> Self {
+        DoubleInt(-self.0)
+    }
+
+    /// Saturating absolute value. Computes `self.abs()`.
+    ///
+    /// The absolute value can never leave the double-int bounds; provided for parity with
+    /// primitive integers.
+    pub const fn saturating_abs(self) -> Self {
+        DoubleInt(self.0.abs())
+    }
+
+    /// Saturating exponentiation. Computes `self.pow(exp)`, clamping the result to the double-int
+    /// bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use double_int::DoubleInt;
+    /// assert_eq!(DoubleInt::from(2).saturating_pow(53), DoubleInt::MAX);
+    /// assert_eq!(DoubleInt::from(-2).saturating_pow(127), DoubleInt::MIN);
+    /// ```
+    pub const fn saturating_pow(self, exp: u32) -> Self {
+        match (self.0 as i128).checked_pow(exp) {
+            Some(val) => DoubleInt::saturating(val),
+            None if self.0 < 0 && exp % 2 == 1 => DoubleInt::MIN,
+            None => DoubleInt::MAX,
+        }
+    }
+
+    /// Wrapping addition. Computes `self + rhs`, wrapping around at the double-int bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use double_int::DoubleInt;
+    /// assert_eq!(DoubleInt::MAX.wrapping_add(DoubleInt::from(1)), DoubleInt::MIN);
+    /// ```
+    pub const fn wrapping_add(self, rhs: Self) -> Self {
+        DoubleInt::wrapping(self.0 as i128 + rhs.0 as i128)
+    }
+
+    /// Wrapping subtraction. Computes `self - rhs`, wrapping around at the double-int bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use double_int::DoubleInt;
+    /// assert_eq!(DoubleInt::MIN.wrapping_sub(DoubleInt::from(1)), DoubleInt::MAX);
+    /// ```
+    pub const fn wrapping_sub(self, rhs: Self) -> Self {
+        DoubleInt::wrapping(self.0 as i128 - rhs.0 as i128)
+    }
+
+    /// Wrapping multiplication. Computes `self * rhs`, wrapping around at the double-int bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use double_int::DoubleInt;
+    /// assert_eq!(DoubleInt::MAX.wrapping_mul(DoubleInt::from(2)), -1);
+    /// ```
+    pub const fn wrapping_mul(self, rhs: Self) -> Self {
+        DoubleInt::wrapping(self.0 as i128 * rhs.0 as i128)
+    }
+
+    /// Wrapping division. Computes `self / rhs`.
+    ///
+    /// Division can never leave the double-int bounds; provided for parity with primitive
+    /// integers.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rhs` is zero.
+    pub const fn wrapping_div(self, rhs: Self) -> Self {
+        DoubleInt(self.0 / rhs.0)
+    }
+
+    /// Wrapping remainder. Computes `self % rhs`.
+    ///
+    /// The remainder can never leave the double-int bounds; provided for parity with primitive
+    /// integers.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rhs` is zero.
+    pub const fn wrapping_rem(self, rhs: Self) -> Self {
+        DoubleInt(self.0 % rhs.0)
+    }
+
+    /// Wrapping negation. Computes `-self`.
+    ///
+    /// Negation can never leave the double-int bounds; provided for parity with primitive
+    /// integers.
+    pub const fn wrapping_neg(self) -> Self {
+        DoubleInt(-self.0)
+    }
+
+    /// Wrapping absolute value. Computes `self.abs()`.
+    ///
+    /// The absolute value can never leave the double-int bounds; provided for parity with
+    /// primitive integers.
+    pub const fn wrapping_abs(self) -> Self {
+        DoubleInt(self.0.abs())
+    }
+
+    /// Wrapping exponentiation. Computes `self.pow(exp)`, wrapping around at the double-int
+    /// bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use double_int::DoubleInt;
+    /// assert_eq!(DoubleInt::from(3).wrapping_pow(4), 81);
+    /// assert_eq!(DoubleInt::from(2).wrapping_pow(54), 1);
+    /// ```
+    pub const fn wrapping_pow(self, mut exp: u32) -> Self {
+        let mut base = self;
+        let mut acc = DoubleInt(1);
+
+        // exponentiation by squaring; wrapping after each step is sound since the double-int
+        // range forms a ring under wrapping arithmetic
+        while exp > 0 {
+            if exp % 2 == 1 {
+                acc = acc.wrapping_mul(base);
+            }
+
+            exp /= 2;
+
+            if exp > 0 {
+                base = base.wrapping_mul(base);
+            }
+        }
+
+        acc
+    }
+
+    /// Computes `self + rhs`, returning the wrapped result along with a boolean indicating whether
+    /// the double-int bounds were exceeded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use double_int::DoubleInt;
+    /// assert_eq!(DoubleInt::from(1).overflowing_add(DoubleInt::from(2)), (DoubleInt::from(3), false));
+    /// assert_eq!(DoubleInt::MAX.overflowing_add(DoubleInt::from(1)), (DoubleInt::MIN, true));
+    /// ```
+    pub const fn overflowing_add(self, rhs: Self) -> (Self, bool) {
+        DoubleInt::overflowing(self.0 as i128 + rhs.0 as i128)
+    }
+
+    /// Computes `self - rhs`, returning the wrapped result along with a boolean indicating whether
+    /// the double-int bounds were exceeded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use double_int::DoubleInt;
+    /// assert_eq!(DoubleInt::MIN.overflowing_sub(DoubleInt::from(1)), (DoubleInt::MAX, true));
+    /// ```
+    pub const fn overflowing_sub(self, rhs: Self) -> (Self, bool) {
+        DoubleInt::overflowing(self.0 as i128 - rhs.0 as i128)
+    }
+
+    /// Computes `self * rhs`, returning the wrapped result along with a boolean indicating whether
+    /// the double-int bounds were exceeded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use double_int::DoubleInt;
+    /// assert_eq!(DoubleInt::MAX.overflowing_mul(DoubleInt::from(2)), (DoubleInt::from(-1), true));
+    /// ```
+    pub const fn overflowing_mul(self, rhs: Self) -> (Self, bool) {
+        DoubleInt::overflowing(self.0 as i128 * rhs.0 as i128)
+    }
+
+    /// Computes `self / rhs`, returning the result along with a boolean indicating whether the
+    /// double-int bounds were exceeded.
+    ///
+    /// Division can never leave the double-int bounds so the boolean is always `false`; provided
+    /// for parity with primitive integers.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rhs` is zero.
+    pub const fn overflowing_div(self, rhs: Self) -> (Self, bool) {
+        (DoubleInt(self.0 / rhs.0), false)
+    }
+
+    /// Computes `self % rhs`, returning the result along with a boolean indicating whether the
+    /// double-int bounds were exceeded.
+    ///
+    /// The remainder can never leave the double-int bounds so the boolean is always `false`;
+    /// provided for parity with primitive integers.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rhs` is zero.
+    pub const fn overflowing_rem(self, rhs: Self) -> (Self, bool) {
+        (DoubleInt(self.0 % rhs.0), false)
+    }
+
+    /// Computes `-self`, returning the result along with a boolean indicating whether the
+    /// double-int bounds were exceeded.
+    ///
+    /// Negation can never leave the double-int bounds so the boolean is always `false`; provided
+    /// for parity with primitive integers.
+    pub const fn overflowing_neg(self) -> (Self, bool) {
+        (DoubleInt(-self.0), false)
+    }
+
+    /// Computes `self.abs()`, returning the result along with a boolean indicating whether the
+    /// double-int bounds were exceeded.
+    ///
+    /// The absolute value can never leave the double-int bounds so the boolean is always `false`;
+    /// provided for parity with primitive integers.
+    pub const fn overflowing_abs(self) -> (Self, bool) {
+        (DoubleInt(self.0.abs()), false)
+    }
+
+    /// Computes `self.pow(exp)`, returning the wrapped result along with a boolean indicating
+    /// whether the double-int bounds were exceeded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use double_int::DoubleInt;
+    /// assert_eq!(DoubleInt::from(3).overflowing_pow(4), (DoubleInt::from(81), false));
+    /// assert_eq!(DoubleInt::from(2).overflowing_pow(54), (DoubleInt::from(1), true));
+    /// ```
+    pub const fn overflowing_pow(self, exp: u32) -> (Self, bool) {
+        (self.wrapping_pow(exp), self.checked_pow(exp).is_none())
+    }
+
+    /// Computes the absolute value of `self`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use double_int::DoubleInt;
+    /// assert_eq!(DoubleInt::MIN.abs(), DoubleInt::MAX);
+    /// ```
+    pub const fn abs(self) -> Self {
+        DoubleInt(self.0.abs())
+    }
+
+    /// Returns a number representing sign of `self`.
+    ///
+    /// - `0` if the number is zero
+    /// - `1` if the number is positive
+    /// - `-1` if the number is negative
+    pub const fn signum(self) -> Self {
+        DoubleInt(self.0.signum())
+    }
+
+    /// Returns `true` if `self` is positive and `false` if the number is zero or negative.
+    pub const fn is_positive(self) -> bool {
+        self.0.is_positive()
+    }
+
+    /// Returns `true` if `self` is negative and `false` if the number is zero or positive.
+    pub const fn is_negative(self) -> bool {
+        self.0.is_negative()
+    }
+}
@@ -0,0 +1,223 @@
+use core::{
+    fmt,
+    sync::atomic::{AtomicI64, Ordering},
+};
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::DoubleInt;
+
+/// Double-int that can be safely shared between threads.
+///
+/// Wraps an [`AtomicI64`] and never stores a value outside the double-int bounds. Arithmetic is
+/// provided by [`checked_fetch_add()`](Self::checked_fetch_add) and friends, which either fail or
+/// saturate at the double-int bounds rather than wrapping.
+///
+/// Serializes the current value, loaded with [`Ordering::Relaxed`].
+///
+/// Requires the `atomic` crate feature, which is only supported on targets with 64-bit atomics.
+///
+/// # Examples
+///
+/// ```
+/// # use core::sync::atomic::Ordering;
+/// # use double_int::{AtomicDoubleInt, DoubleInt};
+/// static REQUESTS: AtomicDoubleInt = AtomicDoubleInt::new(DoubleInt::MAX);
+///
+/// assert!(REQUESTS.checked_fetch_add(DoubleInt::from(1), Ordering::Relaxed).is_none());
+/// assert_eq!(
+///     REQUESTS.saturating_fetch_add(DoubleInt::from(1), Ordering::Relaxed),
+///     DoubleInt::MAX,
+/// );
+///
+/// assert_eq!(REQUESTS.load(Ordering::Relaxed), DoubleInt::MAX);
+/// assert_eq!(serde_json::to_string(&REQUESTS).unwrap(), "9007199254740991");
+/// ```
+#[derive(Default)]
+pub struct AtomicDoubleInt(AtomicI64);
+
+impl AtomicDoubleInt {
+    /// Constructs a new atomic double-int.
+    pub const fn new(val: DoubleInt) -> Self {
+        AtomicDoubleInt(AtomicI64::new(val.0))
+    }
+
+    /// Consumes the atomic and returns the contained value.
+    pub fn into_inner(self) -> DoubleInt {
+        DoubleInt(self.0.into_inner())
+    }
+
+    /// Loads the value.
+    ///
+    /// See [`AtomicI64::load()`] for valid orderings.
+    pub fn load(&self, order: Ordering) -> DoubleInt {
+        DoubleInt(self.0.load(order))
+    }
+
+    /// Stores a value.
+    ///
+    /// See [`AtomicI64::store()`] for valid orderings.
+    pub fn store(&self, val: DoubleInt, order: Ordering) {
+        self.0.store(val.0, order);
+    }
+
+    /// Stores a value, returning the previous value.
+    pub fn swap(&self, val: DoubleInt, order: Ordering) -> DoubleInt {
+        DoubleInt(self.0.swap(val.0, order))
+    }
+
+    /// Stores `new` if the current value is equal to `current`.
+    ///
+    /// Returns the previous value, as `Ok` if it was replaced and `Err` otherwise. See
+    /// [`AtomicI64::compare_exchange()`] for valid orderings.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use core::sync::atomic::Ordering;
+    /// # use double_int::{AtomicDoubleInt, DoubleInt};
+    /// let cursor = AtomicDoubleInt::new(DoubleInt::from(5));
+    ///
+    /// assert_eq!(
+    ///     cursor.compare_exchange(
+    ///         DoubleInt::from(5),
+    ///         DoubleInt::from(10),
+    ///         Ordering::AcqRel,
+    ///         Ordering::Acquire,
+    ///     ),
+    ///     Ok(DoubleInt::from(5)),
+    /// );
+    /// assert_eq!(
+    ///     cursor.compare_exchange(
+    ///         DoubleInt::from(5),
+    ///         DoubleInt::from(15),
+    ///         Ordering::AcqRel,
+    ///         Ordering::Acquire,
+    ///     ),
+    ///     Err(DoubleInt::from(10)),
+    /// );
+    /// ```
+    pub fn compare_exchange(
+        &self,
+        current: DoubleInt,
+        new: DoubleInt,
+        success: Ordering,
+        failure: Ordering,
+    ) -> Result<DoubleInt, DoubleInt> {
+        self.0
+            .compare_exchange(current.0, new.0, success, failure)
+            .map(DoubleInt)
+            .map_err(DoubleInt)
+    }
+
+    /// Stores `new` if the current value is equal to `current`, possibly failing spuriously.
+    ///
+    /// See [`AtomicI64::compare_exchange_weak()`] for details.
+    pub fn compare_exchange_weak(
+        &self,
+        current: DoubleInt,
+        new: DoubleInt,
+        success: Ordering,
+        failure: Ordering,
+    ) -> Result<DoubleInt, DoubleInt> {
+        self.0
+            .compare_exchange_weak(current.0, new.0, success, failure)
+            .map(DoubleInt)
+            .map_err(DoubleInt)
+    }
+
+    /// Adds to the current value, returning the previous value.
+    ///
+    /// Returns `None` and leaves the value unchanged if the result would be outside the double-int
+    /// bounds.
+    pub fn checked_fetch_add(&self, val: DoubleInt, order: Ordering) -> Option<DoubleInt> {
+        self.fetch_update(order, |prev| prev.checked_add(val)).ok()
+    }
+
+    /// Subtracts from the current value, returning the previous value.
+    ///
+    /// Returns `None` and leaves the value unchanged if the result would be outside the double-int
+    /// bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use core::sync::atomic::Ordering;
+    /// # use double_int::{AtomicDoubleInt, DoubleInt};
+    /// let balance = AtomicDoubleInt::new(DoubleInt::MIN + 1);
+    ///
+    /// assert_eq!(
+    ///     balance.checked_fetch_sub(DoubleInt::from(1), Ordering::Relaxed),
+    ///     Some(DoubleInt::MIN + 1),
+    /// );
+    /// assert!(balance.checked_fetch_sub(DoubleInt::from(1), Ordering::Relaxed).is_none());
+    /// assert_eq!(balance.into_inner(), DoubleInt::MIN);
+    /// ```
+    pub fn checked_fetch_sub(&self, val: DoubleInt, order: Ordering) -> Option<DoubleInt> {
+        self.fetch_update(order, |prev| prev.checked_sub(val)).ok()
+    }
+
+    /// Adds to the current value, clamping the result to the double-int bounds, and returns the
+    /// previous value.
+    pub fn saturating_fetch_add(&self, val: DoubleInt, order: Ordering) -> DoubleInt {
+        match self.fetch_update(order, |prev| Some(prev.saturating_add(val))) {
+            Ok(prev) | Err(prev) => prev,
+        }
+    }
+
+    /// Subtracts from the current value, clamping the result to the double-int bounds, and returns
+    /// the previous value.
+    pub fn saturating_fetch_sub(&self, val: DoubleInt, order: Ordering) -> DoubleInt {
+        match self.fetch_update(order, |prev| Some(prev.saturating_sub(val))) {
+            Ok(prev) | Err(prev) => prev,
+        }
+    }
+
+    /// Applies `f` to the current value until it is stored or `f` returns `None`.
+    ///
+    /// Returns the previous value, as `Ok` if it was replaced and `Err` otherwise.
+    ///
+    /// Loads use an ordering derived from `order` in the same way as [`AtomicI64::fetch_add()`].
+    fn fetch_update(
+        &self,
+        order: Ordering,
+        mut f: impl FnMut(DoubleInt) -> Option<DoubleInt>,
+    ) -> Result<DoubleInt, DoubleInt> {
+        let fetch_order = match order {
+            Ordering::Release => Ordering::Relaxed,
+            Ordering::AcqRel => Ordering::Acquire,
+            order => order,
+        };
+
+        self.0
+            .fetch_update(order, fetch_order, |prev| {
+                f(DoubleInt(prev)).map(|val| val.0)
+            })
+            .map(DoubleInt)
+            .map_err(DoubleInt)
+    }
+}
+
+impl From<DoubleInt> for AtomicDoubleInt {
+    fn from(val: DoubleInt) -> Self {
+        AtomicDoubleInt::new(val)
+    }
+}
+
+impl fmt::Debug for AtomicDoubleInt {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&self.load(Ordering::Relaxed), f)
+    }
+}
+
+impl<'de> Deserialize<'de> for AtomicDoubleInt {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        DoubleInt::deserialize(deserializer).map(AtomicDoubleInt::new)
+    }
+}
+
+impl Serialize for AtomicDoubleInt {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.load(Ordering::Relaxed).serialize(serializer)
+    }
+}
@@ -0,0 +1,322 @@
+use core::{cmp::Ordering, fmt};
+
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::{DoubleInt, DoubleIntError};
+
+/// Double-int restricted to the range `MIN..=MAX`.
+///
+/// This represents OpenAPI fields with `format: double-int` and additional `minimum` and `maximum`
+/// constraints. The bounds are checked at compile time and must lie within the double-int bounds,
+/// with `MIN <= MAX`. Values are checked against the bounds on construction, conversion and
+/// deserialization.
+///
+/// Arithmetic is not implemented directly; convert into a [`DoubleInt`] and back instead.
+///
+/// # Examples
+///
+/// ```
+/// # use double_int::{BoundedDoubleInt, DoubleInt};
+/// type PageSize = BoundedDoubleInt<1, 500>;
+///
+/// let size = serde_json::from_str::<PageSize>("50").unwrap();
+/// assert_eq!(size, 50);
+/// assert_eq!(DoubleInt::from(size) * 2, 100);
+///
+/// assert_eq!(
+///     serde_json::from_str::<PageSize>("501").unwrap_err().to_string(),
+///     "value 501 is outside the range 1..=500 at line 1 column 3",
+/// );
+/// serde_json::from_str::<PageSize>("0").unwrap_err();
+/// serde_json::from_str::<PageSize>("4.2").unwrap_err();
+///
+/// PageSize::try_from(DoubleInt::from(42)).unwrap();
+/// PageSize::try_from(DoubleInt::MAX).unwrap_err();
+/// ```
+///
+/// Bounds outside the double-int bounds fail to compile:
+///
+/// ```compile_fail
+/// # use double_int::BoundedDoubleInt;
+/// let size = BoundedDoubleInt::<0, 9_007_199_254_740_992>::new(42);
+/// ```
+///
+/// As do empty ranges:
+///
+/// ```compile_fail
+/// # use double_int::BoundedDoubleInt;
+/// let size = BoundedDoubleInt::<500, 1>::new(42);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct BoundedDoubleInt<const MIN: i64, const MAX: i64>(DoubleInt);
+
+impl<const MIN: i64, const MAX: i64> BoundedDoubleInt<MIN, MAX> {
+    /// The smallest value that can be represented by this type, `MIN`.
+    pub const MIN: Self = BoundedDoubleInt(bound_or_fail(MIN, MAX, MIN));
+
+    /// The largest value that can be represented by this type, `MAX`.
+    pub const MAX: Self = BoundedDoubleInt(bound_or_fail(MIN, MAX, MAX));
+
+    /// Constructs a new bounded double-int, returning `None` if `val` is outside `MIN..=MAX`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use double_int::BoundedDoubleInt;
+    /// type Percentage = BoundedDoubleInt<0, 100>;
+    ///
+    /// const HALF: Option<Percentage> = Percentage::new(50);
+    /// assert_eq!(HALF.unwrap(), 50);
+    ///
+    /// assert!(Percentage::new(101).is_none());
+    /// ```
+    pub const fn new(val: i64) -> Option<Self> {
+        match BoundedDoubleInt::try_new(val) {
+            Ok(val) => Some(val),
+            Err(_) => None,
+        }
+    }
+
+    /// Constructs a new bounded double-int, returning an error if `val` is outside `MIN..=MAX`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use double_int::{BoundedDoubleInt, DoubleIntError};
+    /// type Percentage = BoundedDoubleInt<0, 100>;
+    ///
+    /// assert_eq!(
+    ///     Percentage::try_new(-1).unwrap_err(),
+    ///     DoubleIntError::OutOfRange { value: -1, min: 0, max: 100 },
+    /// );
+    /// ```
+    pub const fn try_new(val: i64) -> Result<Self, DoubleIntError> {
+        BoundedDoubleInt::from_i128(val as i128)
+    }
+
+    /// Constructs a new bounded double-int without checking that `val` is within `MIN..=MAX`.
+    ///
+    /// # Safety
+    ///
+    /// `val` must be within the range `MIN..=MAX`.
+    pub const unsafe fn new_unchecked(val: i64) -> Self {
+        BoundedDoubleInt(DoubleInt(val))
+    }
+
+    /// Returns value as a standard type.
+    pub const fn as_i64(self) -> i64 {
+        self.0 .0
+    }
+
+    /// Returns value as an unbounded double-int.
+    pub const fn as_double_int(self) -> DoubleInt {
+        self.0
+    }
+
+    /// Converts a signed integer, checking that it lies within `MIN..=MAX`.
+    pub(crate) const fn from_i128(val: i128) -> Result<Self, DoubleIntError> {
+        if val < Self::MIN.0 .0 as i128 || val > Self::MAX.0 .0 as i128 {
+            Err(DoubleIntError::OutOfRange {
+                value: val,
+                min: MIN,
+                max: MAX,
+            })
+        } else {
+            Ok(BoundedDoubleInt(DoubleInt(val as i64)))
+        }
+    }
+
+    /// Converts an unsigned integer, checking that it lies within `MIN..=MAX`.
+    const fn from_u128(val: u128) -> Result<Self, DoubleIntError> {
+        if val > i128::MAX as u128 {
+            BoundedDoubleInt::from_i128(i128::MAX)
+        } else {
+            BoundedDoubleInt::from_i128(val as i128)
+        }
+    }
+}
+
+/// Returns `val` as a double-int, failing const evaluation if `min..=max` is empty or not within
+/// the double-int bounds.
+const fn bound_or_fail(min: i64, max: i64, val: i64) -> DoubleInt {
+    let valid = min <= max && min >= DoubleInt::MIN.0 && max <= DoubleInt::MAX.0;
+
+    // see `new_or_fail` for why indexing out of bounds is used
+    let bounds_empty_or_out_of_double_int_bounds = [DoubleInt(val)];
+    bounds_empty_or_out_of_double_int_bounds[!valid as usize]
+}
+
+macro_rules! try_from_impl {
+    ($ty:ty, $conv:ident, $wide:ty) => {
+        impl<const MIN: i64, const MAX: i64> TryFrom<$ty> for BoundedDoubleInt<MIN, MAX> {
+            type Error = DoubleIntError;
+
+            fn try_from(val: $ty) -> Result<Self, Self::Error> {
+                BoundedDoubleInt::$conv(val as $wide)
+            }
+        }
+    };
+}
+
+try_from_impl!(u8, from_i128, i128);
+try_from_impl!(u16, from_i128, i128);
+try_from_impl!(u32, from_i128, i128);
+try_from_impl!(u64, from_i128, i128);
+try_from_impl!(u128, from_u128, u128);
+try_from_impl!(usize, from_u128, u128);
+try_from_impl!(i8, from_i128, i128);
+try_from_impl!(i16, from_i128, i128);
+try_from_impl!(i32, from_i128, i128);
+try_from_impl!(i64, from_i128, i128);
+try_from_impl!(i128, from_i128, i128);
+try_from_impl!(isize, from_i128, i128);
+
+impl<const MIN: i64, const MAX: i64> TryFrom<DoubleInt> for BoundedDoubleInt<MIN, MAX> {
+    type Error = DoubleIntError;
+
+    fn try_from(val: DoubleInt) -> Result<Self, Self::Error> {
+        BoundedDoubleInt::from_i128(val.0 as i128)
+    }
+}
+
+impl<const MIN: i64, const MAX: i64> TryFrom<f64> for BoundedDoubleInt<MIN, MAX> {
+    type Error = DoubleIntError;
+
+    /// Converts a float into a bounded double-int, failing if the conversion would lose precision.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use double_int::{BoundedDoubleInt, DoubleIntError};
+    /// type Percentage = BoundedDoubleInt<0, 100>;
+    ///
+    /// assert_eq!(Percentage::try_from(42.0_f64).unwrap(), 42);
+    ///
+    /// assert!(matches!(Percentage::try_from(4.2_f64), Err(DoubleIntError::NotIntegral(_))));
+    /// assert!(matches!(Percentage::try_from(101.0_f64), Err(DoubleIntError::OutOfRange { .. })));
+    /// assert!(matches!(Percentage::try_from(1e20_f64), Err(DoubleIntError::OutOfRange { .. })));
+    /// ```
+    fn try_from(val: f64) -> Result<Self, Self::Error> {
+        match DoubleInt::from_f64(val) {
+            Ok(val) => BoundedDoubleInt::try_from(val),
+            // float to int casts saturate
+            Err(DoubleIntError::TooLarge(_) | DoubleIntError::TooSmall(_)) => {
+                BoundedDoubleInt::from_i128(val as i128)
+            }
+            Err(err) => Err(err),
+        }
+    }
+}
+
+impl<const MIN: i64, const MAX: i64> TryFrom<f32> for BoundedDoubleInt<MIN, MAX> {
+    type Error = DoubleIntError;
+
+    /// Converts a float into a bounded double-int, failing if the conversion would lose precision.
+    fn try_from(val: f32) -> Result<Self, Self::Error> {
+        BoundedDoubleInt::try_from(f64::from(val))
+    }
+}
+
+impl<const MIN: i64, const MAX: i64> From<BoundedDoubleInt<MIN, MAX>> for DoubleInt {
+    fn from(val: BoundedDoubleInt<MIN, MAX>) -> Self {
+        val.0
+    }
+}
+
+impl<const MIN: i64, const MAX: i64> From<BoundedDoubleInt<MIN, MAX>> for i64 {
+    fn from(val: BoundedDoubleInt<MIN, MAX>) -> Self {
+        val.0 .0
+    }
+}
+
+impl<const MIN: i64, const MAX: i64> From<BoundedDoubleInt<MIN, MAX>> for i128 {
+    fn from(val: BoundedDoubleInt<MIN, MAX>) -> Self {
+        val.0 .0 as i128
+    }
+}
+
+impl<const MIN: i64, const MAX: i64> From<BoundedDoubleInt<MIN, MAX>> for f64 {
+    fn from(val: BoundedDoubleInt<MIN, MAX>) -> Self {
+        f64::from(val.0)
+    }
+}
+
+macro_rules! cmp_impls {
+    ($($ty:ty),+) => {$(
+        impl<const MIN: i64, const MAX: i64> PartialEq<$ty> for BoundedDoubleInt<MIN, MAX> {
+            fn eq(&self, val: &$ty) -> bool {
+                self.0 == *val
+            }
+        }
+
+        impl<const MIN: i64, const MAX: i64> PartialEq<BoundedDoubleInt<MIN, MAX>> for $ty {
+            fn eq(&self, val: &BoundedDoubleInt<MIN, MAX>) -> bool {
+                *self == val.0
+            }
+        }
+
+        impl<const MIN: i64, const MAX: i64> PartialOrd<$ty> for BoundedDoubleInt<MIN, MAX> {
+            fn partial_cmp(&self, val: &$ty) -> Option<Ordering> {
+                self.0.partial_cmp(val)
+            }
+        }
+
+        impl<const MIN: i64, const MAX: i64> PartialOrd<BoundedDoubleInt<MIN, MAX>> for $ty {
+            fn partial_cmp(&self, val: &BoundedDoubleInt<MIN, MAX>) -> Option<Ordering> {
+                self.partial_cmp(&val.0)
+            }
+        }
+    )+};
+}
+
+// comparisons are delegated to the inner double-int
+cmp_impls!(DoubleInt, u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64);
+
+impl<const MIN: i64, const MAX: i64> fmt::Display for BoundedDoubleInt<MIN, MAX> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.0, f)
+    }
+}
+
+impl<'de, const MIN: i64, const MAX: i64> Deserialize<'de> for BoundedDoubleInt<MIN, MAX> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_i64(BoundedDoubleIntVisitor)
+    }
+}
+
+impl<const MIN: i64, const MAX: i64> Serialize for BoundedDoubleInt<MIN, MAX> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.0.serialize(serializer)
+    }
+}
+
+/// Visitor that accepts integers and numeric strings within `MIN..=MAX`.
+struct BoundedDoubleIntVisitor<const MIN: i64, const MAX: i64>;
+
+impl<'de, const MIN: i64, const MAX: i64> de::Visitor<'de> for BoundedDoubleIntVisitor<MIN, MAX> {
+    type Value = BoundedDoubleInt<MIN, MAX>;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "an integer in the range {}..={}", MIN, MAX)
+    }
+
+    fn visit_i64<E: de::Error>(self, val: i64) -> Result<Self::Value, E> {
+        BoundedDoubleInt::try_from(val).map_err(E::custom)
+    }
+
+    fn visit_i128<E: de::Error>(self, val: i128) -> Result<Self::Value, E> {
+        BoundedDoubleInt::try_from(val).map_err(E::custom)
+    }
+
+    fn visit_u64<E: de::Error>(self, val: u64) -> Result<Self::Value, E> {
+        BoundedDoubleInt::try_from(val).map_err(E::custom)
+    }
+
+    fn visit_u128<E: de::Error>(self, val: u128) -> Result<Self::Value, E> {
+        BoundedDoubleInt::try_from(val).map_err(E::custom)
+    }
+
+    fn visit_str<E: de::Error>(self, val: &str) -> Result<Self::Value, E> {
+        val.parse().map_err(E::custom)
+    }
+}
@@ -0,0 +1,255 @@
+use core::{cmp::Ordering, fmt, str::FromStr};
+
+use serde::{de, Serialize};
+
+use crate::{DoubleInt, DoubleIntError, DoubleUint, I64AsString, I64OrString, SingleInt};
+
+/// Integer types supported by the [`lenient`](crate::lenient),
+/// [`number_or_string`](crate::number_or_string) and [`string_keys`](crate::string_keys) modules.
+///
+/// Not nameable outside this crate, so it cannot be implemented for other types.
+pub trait Integer:
+    Copy
+    + fmt::Display
+    + FromStr<Err = DoubleIntError>
+    + TryFrom<f64, Error = DoubleIntError>
+    + Serialize
+{
+    /// Name of the type's bounds used in deserialization errors.
+    const NAME: &'static str;
+
+    /// Converts a signed integer, checking that it lies within the type's bounds.
+    fn from_i128(val: i128) -> Result<Self, DoubleIntError>;
+
+    /// Converts an unsigned integer, checking that it lies within the type's bounds.
+    fn from_u128(val: u128) -> Result<Self, DoubleIntError>;
+}
+
+impl Integer for DoubleInt {
+    const NAME: &'static str = "double-int";
+
+    fn from_i128(val: i128) -> Result<Self, DoubleIntError> {
+        DoubleInt::from_i128(val)
+    }
+
+    fn from_u128(val: u128) -> Result<Self, DoubleIntError> {
+        DoubleInt::from_u128(val)
+    }
+}
+
+impl Integer for DoubleUint {
+    const NAME: &'static str = "double-uint";
+
+    fn from_i128(val: i128) -> Result<Self, DoubleIntError> {
+        DoubleUint::from_i128(val)
+    }
+
+    fn from_u128(val: u128) -> Result<Self, DoubleIntError> {
+        DoubleUint::from_u128(val)
+    }
+}
+
+impl Integer for SingleInt {
+    const NAME: &'static str = "single-int";
+
+    fn from_i128(val: i128) -> Result<Self, DoubleIntError> {
+        SingleInt::from_i128(val)
+    }
+
+    fn from_u128(val: u128) -> Result<Self, DoubleIntError> {
+        SingleInt::from_u128(val)
+    }
+}
+
+macro_rules! int_impls {
+    (
+        $ty:ident($inner:ty) $(=> $visitor:ident, $expecting:literal)?;
+        from $($from:ty),+;
+        try_from $($signed:ty),+ => $from_i128:ident;
+        try_from $($unsigned:ty),+ => $from_u128:ident;
+        cmp $($other:ty),*;
+    ) => {
+        $(
+            impl From<$from> for $ty {
+                fn from(val: $from) -> Self {
+                    $ty(val as $inner)
+                }
+            }
+        )+
+
+        $(
+            impl TryFrom<$signed> for $ty {
+                type Error = DoubleIntError;
+
+                fn try_from(val: $signed) -> Result<Self, Self::Error> {
+                    $ty::$from_i128(val as i128)
+                }
+            }
+        )+
+
+        $(
+            impl TryFrom<$unsigned> for $ty {
+                type Error = DoubleIntError;
+
+                fn try_from(val: $unsigned) -> Result<Self, Self::Error> {
+                    $ty::$from_u128(val as u128)
+                }
+            }
+        )+
+
+        cmp_impls!($ty, |this, val: u8| Some(this.cmp(&(val as i128))));
+        cmp_impls!($ty, |this, val: u16| Some(this.cmp(&(val as i128))));
+        cmp_impls!($ty, |this, val: u32| Some(this.cmp(&(val as i128))));
+        cmp_impls!($ty, |this, val: u64| Some(this.cmp(&(val as i128))));
+        cmp_impls!($ty, |this, val: usize| Some(this.cmp(&(val as i128))));
+        cmp_impls!($ty, |this, val: i8| Some(this.cmp(&(val as i128))));
+        cmp_impls!($ty, |this, val: i16| Some(this.cmp(&(val as i128))));
+        cmp_impls!($ty, |this, val: i32| Some(this.cmp(&(val as i128))));
+        cmp_impls!($ty, |this, val: i64| Some(this.cmp(&(val as i128))));
+        cmp_impls!($ty, |this, val: i128| Some(this.cmp(&val)));
+        cmp_impls!($ty, |this, val: isize| Some(this.cmp(&(val as i128))));
+        cmp_impls!($ty, |this, val: u128| cmp_u128(this, val));
+        cmp_impls!($ty, |this, val: f64| cmp_f64(this, val));
+        cmp_impls!($ty, |this, val: f32| cmp_f64(this, f64::from(val)));
+        $(cmp_impls!($ty, |this, val: $other| Some(this.cmp(&i128::from(val))));)*
+
+        $(
+            /// Visitor that accepts integers and numeric strings within the bounds of the type.
+            pub(crate) struct $visitor;
+
+            impl<'de> de::Visitor<'de> for $visitor {
+                type Value = $ty;
+
+                fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    f.write_str($expecting)
+                }
+
+                fn visit_i64<E: de::Error>(self, val: i64) -> Result<Self::Value, E> {
+                    $ty::$from_i128(val as i128).map_err(E::custom)
+                }
+
+                fn visit_i128<E: de::Error>(self, val: i128) -> Result<Self::Value, E> {
+                    $ty::$from_i128(val).map_err(E::custom)
+                }
+
+                fn visit_u64<E: de::Error>(self, val: u64) -> Result<Self::Value, E> {
+                    $ty::$from_u128(val as u128).map_err(E::custom)
+                }
+
+                fn visit_u128<E: de::Error>(self, val: u128) -> Result<Self::Value, E> {
+                    $ty::$from_u128(val).map_err(E::custom)
+                }
+
+                fn visit_str<E: de::Error>(self, val: &str) -> Result<Self::Value, E> {
+                    val.parse().map_err(E::custom)
+                }
+            }
+        )?
+    };
+}
+
+macro_rules! cmp_impls {
+    ($ty:ident, |$this:ident, $val:ident: $other:ty| $cmp:expr) => {
+        impl PartialEq<$other> for $ty {
+            fn eq(&self, val: &$other) -> bool {
+                self.partial_cmp(val) == Some(Ordering::Equal)
+            }
+        }
+
+        impl PartialEq<$ty> for $other {
+            fn eq(&self, val: &$ty) -> bool {
+                val == self
+            }
+        }
+
+        impl PartialOrd<$other> for $ty {
+            fn partial_cmp(&self, val: &$other) -> Option<Ordering> {
+                let ($this, $val) = (self.0 as i128, *val);
+                $cmp
+            }
+        }
+
+        impl PartialOrd<$ty> for $other {
+            fn partial_cmp(&self, val: &$ty) -> Option<Ordering> {
+                val.partial_cmp(self).map(Ordering::reverse)
+            }
+        }
+    };
+}
+
+/// Compares a widened integer with a `u128`.
+fn cmp_u128(this: i128, val: u128) -> Option<Ordering> {
+    if this < 0 {
+        // negative values are smaller than any u128
+        Some(Ordering::Less)
+    } else {
+        Some((this as u128).cmp(&val))
+    }
+}
+
+/// Compares a widened 64-bit integer with a float exactly; NaN is unordered.
+fn cmp_f64(this: i128, val: f64) -> Option<Ordering> {
+    // 2^64, beyond the reach of any 64-bit integer
+    const LIMIT: f64 = 18_446_744_073_709_551_616.0;
+
+    if val.is_nan() {
+        None
+    } else if val >= LIMIT {
+        Some(Ordering::Less)
+    } else if val <= -LIMIT {
+        Some(Ordering::Greater)
+    } else {
+        // the cast truncates towards zero and the integral part is exactly representable by both
+        // types, so only the sign of the fractional part is needed to break ties
+        let int = val as i128;
+        let fract = val - int as f64;
+
+        Some(this.cmp(&int).then(if fract > 0.0 {
+            Ordering::Less
+        } else if fract < 0.0 {
+            Ordering::Greater
+        } else {
+            Ordering::Equal
+        }))
+    }
+}
+
+int_impls! {
+    DoubleInt(i64) => DoubleIntVisitor, "an integer within the double-int bounds";
+    from u8, u16, u32, i8, i16, i32;
+    try_from i64, i128, isize => from_i128;
+    try_from u64, u128, usize => from_u128;
+    cmp;
+}
+
+int_impls! {
+    DoubleUint(u64) => DoubleUintVisitor, "a non-negative integer within the double-int bounds";
+    from u8, u16, u32;
+    try_from i8, i16, i32, i64, i128, isize => from_i128;
+    try_from u64, u128, usize => from_u128;
+    cmp DoubleInt;
+}
+
+int_impls! {
+    SingleInt(i32) => SingleIntVisitor, "an integer within the single-int bounds";
+    from u8, u16, i8, i16;
+    try_from i32, i64, i128, isize => from_i128;
+    try_from u32, u64, u128, usize => from_u128;
+    cmp DoubleInt;
+}
+
+int_impls! {
+    I64AsString(i64);
+    from u8, u16, u32, i8, i16, i32, i64;
+    try_from i128, isize => from_i128;
+    try_from u64, u128, usize => from_u128;
+    cmp DoubleInt;
+}
+
+int_impls! {
+    I64OrString(i64);
+    from u8, u16, u32, i8, i16, i32, i64;
+    try_from i128, isize => from_i128;
+    try_from u64, u128, usize => from_u128;
+    cmp DoubleInt;
+}
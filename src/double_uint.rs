@@ -0,0 +1,443 @@
+use core::borrow::Borrow;
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::{conv::DoubleUintVisitor, DoubleInt, DoubleIntError};
+
+/// Non-negative integer that can be stored in an IEEE 754 double-precision number without loss of
+/// precision.
+///
+/// Represents values in the range 0..=(2^53) - 1 and otherwise behaves like [`DoubleInt`].
+///
+/// # Examples
+///
+/// ```
+/// # use double_int::DoubleUint;
+/// assert_eq!(serde_json::from_str::<DoubleUint>("42").unwrap(), 42_u64);
+///
+/// serde_json::from_str::<DoubleUint>("-42").unwrap_err();
+/// serde_json::from_str::<DoubleUint>("4.2").unwrap_err();
+/// serde_json::from_str::<DoubleUint>("9007199254740992").unwrap_err();
+///
+/// let count = DoubleUint::from(40_u8);
+/// assert_eq!(count + 2_u8, 42_u64);
+/// assert_eq!(count.to_string(), "40");
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct DoubleUint(pub(crate) u64);
+
+impl DoubleUint {
+    /// The smallest value that can be represented by this type, 0.
+    pub const MIN: DoubleUint = DoubleUint(0);
+
+    /// The largest value that can be represented by this type, (2^53) - 1.
+    pub const MAX: DoubleUint = DoubleUint(2_u64.pow(53) - 1);
+
+    const MAX_I128: i128 = DoubleUint::MAX.0 as i128;
+    const MAX_U128: u128 = DoubleUint::MAX.0 as u128;
+
+    /// Constructs a new double-uint, returning `None` if `val` is outside the double-uint bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use double_int::DoubleUint;
+    /// const COUNT: Option<DoubleUint> = DoubleUint::new(42);
+    /// assert_eq!(COUNT.unwrap(), 42_u64);
+    ///
+    /// assert!(DoubleUint::new(u64::MAX).is_none());
+    /// ```
+    pub const fn new(val: u64) -> Option<Self> {
+        match DoubleUint::try_new(val) {
+            Ok(val) => Some(val),
+            Err(_) => None,
+        }
+    }
+
+    /// Constructs a new double-uint, returning an error if `val` is outside the double-uint
+    /// bounds.
+    pub const fn try_new(val: u64) -> Result<Self, DoubleIntError> {
+        DoubleUint::from_u128(val as u128)
+    }
+
+    /// Constructs a new double-uint without checking that `val` is within the double-uint bounds.
+    ///
+    /// # Safety
+    ///
+    /// `val` must be within the range [`DoubleUint::MIN`]..=[`DoubleUint::MAX`].
+    pub const unsafe fn new_unchecked(val: u64) -> Self {
+        DoubleUint(val)
+    }
+
+    /// Returns value as a standard type.
+    pub const fn as_u64(self) -> u64 {
+        self.0
+    }
+
+    /// Returns value as a double-int.
+    pub const fn as_double_int(self) -> DoubleInt {
+        DoubleInt(self.0 as i64)
+    }
+
+    /// Converts a signed integer, checking that it lies within the double-uint bounds.
+    pub(crate) const fn from_i128(val: i128) -> Result<Self, DoubleIntError> {
+        if val < 0 {
+            Err(DoubleIntError::Negative(val))
+        } else {
+            DoubleUint::from_u128(val as u128)
+        }
+    }
+
+    /// Converts an unsigned integer, checking that it lies within the double-uint bounds.
+    pub(crate) const fn from_u128(val: u128) -> Result<Self, DoubleIntError> {
+        if val > DoubleUint::MAX_U128 {
+            Err(DoubleIntError::TooLarge(val))
+        } else {
+            Ok(DoubleUint(val as u64))
+        }
+    }
+
+    /// Converts a widened result, returning `None` if outside the double-uint bounds.
+    const fn checked(val: i128) -> Option<Self> {
+        match DoubleUint::from_i128(val) {
+            Ok(val) => Some(val),
+            Err(_) => None,
+        }
+    }
+
+    /// Converts a widened result, clamping to the double-uint bounds.
+    const fn saturating(val: i128) -> Self {
+        if val > DoubleUint::MAX_I128 {
+            DoubleUint::MAX
+        } else if val < 0 {
+            DoubleUint::MIN
+        } else {
+            DoubleUint(val as u64)
+        }
+    }
+
+    /// Checked addition. Computes `self + rhs`, returning `None` if the result is outside the
+    /// double-uint bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use double_int::DoubleUint;
+    /// assert_eq!(DoubleUint::from(1_u8).checked_add(DoubleUint::from(2_u8)).unwrap(), 3_u64);
+    /// assert!(DoubleUint::MAX.checked_add(DoubleUint::from(1_u8)).is_none());
+    /// ```
+    pub const fn checked_add(self, rhs: Self) -> Option<Self> {
+        DoubleUint::checked(self.0 as i128 + rhs.0 as i128)
+    }
+
+    /// Checked subtraction. Computes `self - rhs`, returning `None` if the result is negative.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use double_int::DoubleUint;
+    /// assert_eq!(DoubleUint::from(2_u8).checked_sub(DoubleUint::from(1_u8)).unwrap(), 1_u64);
+    /// assert!(DoubleUint::MIN.checked_sub(DoubleUint::from(1_u8)).is_none());
+    /// ```
+    pub const fn checked_sub(self, rhs: Self) -> Option<Self> {
+        DoubleUint::checked(self.0 as i128 - rhs.0 as i128)
+    }
+
+    /// Checked multiplication. Computes `self * rhs`, returning `None` if the result is outside
+    /// the double-uint bounds.
+    pub const fn checked_mul(self, rhs: Self) -> Option<Self> {
+        DoubleUint::checked(self.0 as i128 * rhs.0 as i128)
+    }
+
+    /// Checked division. Computes `self / rhs`, returning `None` if `rhs == 0`.
+    pub const fn checked_div(self, rhs: Self) -> Option<Self> {
+        match self.0.checked_div(rhs.0) {
+            Some(val) => Some(DoubleUint(val)),
+            None => None,
+        }
+    }
+
+    /// Checked remainder. Computes `self % rhs`, returning `None` if `rhs == 0`.
+    pub const fn checked_rem(self, rhs: Self) -> Option<Self> {
+        match self.0.checked_rem(rhs.0) {
+            Some(val) => Some(DoubleUint(val)),
+            None => None,
+        }
+    }
+
+    /// Checked exponentiation. Computes `self.pow(exp)`, returning `None` if the result is outside
+    /// the double-uint bounds.
+    pub const fn checked_pow(self, exp: u32) -> Option<Self> {
+        match (self.0 as u128).checked_pow(exp) {
+            Some(val) => match DoubleUint::from_u128(val) {
+                Ok(val) => Some(val),
+                Err(_) => None,
+            },
+            None => None,
+        }
+    }
+
+    /// Saturating addition. Computes `self + rhs`, clamping the result to the double-uint bounds.
+    pub const fn saturating_add(self, rhs: Self) -> Self {
+        DoubleUint::saturating(self.0 as i128 + rhs.0 as i128)
+    }
+
+    /// Saturating subtraction. Computes `self - rhs`, clamping the result to the double-uint
+    /// bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use double_int::DoubleUint;
+    /// assert_eq!(DoubleUint::from(1_u8).saturating_sub(DoubleUint::from(2_u8)), DoubleUint::MIN);
+    /// ```
+    pub const fn saturating_sub(self, rhs: Self) -> Self {
+        DoubleUint::saturating(self.0 as i128 - rhs.0 as i128)
+    }
+
+    /// Saturating multiplication. Computes `self * rhs`, clamping the result to the double-uint
+    /// bounds.
+    pub const fn saturating_mul(self, rhs: Self) -> Self {
+        DoubleUint::saturating(self.0 as i128 * rhs.0 as i128)
+    }
+
+    /// Saturating division. Computes `self / rhs`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rhs` is zero.
+    pub const fn saturating_div(self, rhs: Self) -> Self {
+        DoubleUint(self.0 / rhs.0)
+    }
+
+    /// Saturating exponentiation. Computes `self.pow(exp)`, clamping the result to the
+    /// double-uint bounds.
+    pub const fn saturating_pow(self, exp: u32) -> Self {
+        match self.checked_pow(exp) {
+            Some(val) => val,
+            None => DoubleUint::MAX,
+        }
+    }
+
+    /// Wrapping addition. Computes `self + rhs`, wrapping around at the double-uint bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use double_int::DoubleUint;
+    /// assert_eq!(DoubleUint::MAX.wrapping_add(DoubleUint::from(1_u8)), DoubleUint::MIN);
+    /// ```
+    pub const fn wrapping_add(self, rhs: Self) -> Self {
+        // the double-uint range is a power of two in size so wrapping is a simple mask
+        DoubleUint(self.0.wrapping_add(rhs.0) & DoubleUint::MAX.0)
+    }
+
+    /// Wrapping subtraction. Computes `self - rhs`, wrapping around at the double-uint bounds.
+    pub const fn wrapping_sub(self, rhs: Self) -> Self {
+        DoubleUint(self.0.wrapping_sub(rhs.0) & DoubleUint::MAX.0)
+    }
+
+    /// Wrapping multiplication. Computes `self * rhs`, wrapping around at the double-uint bounds.
+    pub const fn wrapping_mul(self, rhs: Self) -> Self {
+        DoubleUint(self.0.wrapping_mul(rhs.0) & DoubleUint::MAX.0)
+    }
+
+    /// Wrapping division. Computes `self / rhs`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rhs` is zero.
+    pub const fn wrapping_div(self, rhs: Self) -> Self {
+        DoubleUint(self.0 / rhs.0)
+    }
+
+    /// Wrapping remainder. Computes `self % rhs`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rhs` is zero.
+    pub const fn wrapping_rem(self, rhs: Self) -> Self {
+        DoubleUint(self.0 % rhs.0)
+    }
+
+    /// Wrapping exponentiation. Computes `self.pow(exp)`, wrapping around at the double-uint
+    /// bounds.
+    pub const fn wrapping_pow(self, exp: u32) -> Self {
+        DoubleUint(self.0.wrapping_pow(exp) & DoubleUint::MAX.0)
+    }
+
+    /// Computes `self + rhs`, returning the wrapped result along with a boolean indicating whether
+    /// the double-uint bounds were exceeded.
+    pub const fn overflowing_add(self, rhs: Self) -> (Self, bool) {
+        (self.wrapping_add(rhs), self.checked_add(rhs).is_none())
+    }
+
+    /// Computes `self - rhs`, returning the wrapped result along with a boolean indicating whether
+    /// the double-uint bounds were exceeded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use double_int::DoubleUint;
+    /// assert_eq!(
+    ///     DoubleUint::MIN.overflowing_sub(DoubleUint::from(1_u8)),
+    ///     (DoubleUint::MAX, true),
+    /// );
+    /// ```
+    pub const fn overflowing_sub(self, rhs: Self) -> (Self, bool) {
+        (self.wrapping_sub(rhs), self.checked_sub(rhs).is_none())
+    }
+
+    /// Computes `self * rhs`, returning the wrapped result along with a boolean indicating whether
+    /// the double-uint bounds were exceeded.
+    pub const fn overflowing_mul(self, rhs: Self) -> (Self, bool) {
+        (self.wrapping_mul(rhs), self.checked_mul(rhs).is_none())
+    }
+
+    /// Computes `self / rhs`, returning the result along with a boolean indicating whether the
+    /// double-uint bounds were exceeded.
+    ///
+    /// Division can never leave the double-uint bounds so the boolean is always `false`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rhs` is zero.
+    pub const fn overflowing_div(self, rhs: Self) -> (Self, bool) {
+        (DoubleUint(self.0 / rhs.0), false)
+    }
+
+    /// Computes `self % rhs`, returning the result along with a boolean indicating whether the
+    /// double-uint bounds were exceeded.
+    ///
+    /// The remainder can never leave the double-uint bounds so the boolean is always `false`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rhs` is zero.
+    pub const fn overflowing_rem(self, rhs: Self) -> (Self, bool) {
+        (DoubleUint(self.0 % rhs.0), false)
+    }
+
+    /// Computes `self.pow(exp)`, returning the wrapped result along with a boolean indicating
+    /// whether the double-uint bounds were exceeded.
+    pub const fn overflowing_pow(self, exp: u32) -> (Self, bool) {
+        (self.wrapping_pow(exp), self.checked_pow(exp).is_none())
+    }
+}
+
+impl TryFrom<f64> for DoubleUint {
+    type Error = DoubleIntError;
+
+    /// Converts a float into a double-uint, failing if the conversion would lose precision.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use double_int::{DoubleUint, DoubleIntError};
+    /// assert_eq!(DoubleUint::try_from(42.0_f64).unwrap(), 42_u64);
+    /// assert_eq!(DoubleUint::try_from(-0.0_f64).unwrap(), 0_u64);
+    ///
+    /// assert!(matches!(DoubleUint::try_from(-1.0_f64), Err(DoubleIntError::Negative(-1))));
+    /// assert!(matches!(DoubleUint::try_from(4.2_f64), Err(DoubleIntError::NotIntegral(_))));
+    /// ```
+    fn try_from(val: f64) -> Result<Self, Self::Error> {
+        match DoubleInt::from_f64(val) {
+            Ok(val) => DoubleUint::try_from(val),
+            Err(DoubleIntError::TooSmall(val)) => Err(DoubleIntError::Negative(val)),
+            Err(err) => Err(err),
+        }
+    }
+}
+
+impl TryFrom<f32> for DoubleUint {
+    type Error = DoubleIntError;
+
+    /// Converts a float into a double-uint, failing if the conversion would lose precision.
+    fn try_from(val: f32) -> Result<Self, Self::Error> {
+        DoubleUint::try_from(f64::from(val))
+    }
+}
+
+impl TryFrom<DoubleInt> for DoubleUint {
+    type Error = DoubleIntError;
+
+    /// Converts a double-int into a double-uint, failing if it is negative.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use double_int::{DoubleInt, DoubleUint, DoubleIntError};
+    /// assert_eq!(DoubleUint::try_from(DoubleInt::MAX).unwrap(), DoubleUint::MAX);
+    /// assert_eq!(DoubleInt::from(DoubleUint::MAX), DoubleInt::MAX);
+    ///
+    /// assert_eq!(
+    ///     DoubleUint::try_from(DoubleInt::from(-1)).unwrap_err(),
+    ///     DoubleIntError::Negative(-1),
+    /// );
+    /// ```
+    fn try_from(val: DoubleInt) -> Result<Self, Self::Error> {
+        DoubleUint::from_i128(val.0 as i128)
+    }
+}
+
+impl From<DoubleUint> for DoubleInt {
+    fn from(val: DoubleUint) -> Self {
+        val.as_double_int()
+    }
+}
+
+impl From<DoubleUint> for f64 {
+    fn from(val: DoubleUint) -> Self {
+        // all double-uints are exactly representable by f64
+        val.0 as f64
+    }
+}
+
+impl From<DoubleUint> for u64 {
+    fn from(val: DoubleUint) -> Self {
+        val.0
+    }
+}
+
+impl From<DoubleUint> for u128 {
+    fn from(val: DoubleUint) -> Self {
+        val.0 as u128
+    }
+}
+
+impl From<DoubleUint> for i64 {
+    fn from(val: DoubleUint) -> Self {
+        val.0 as i64
+    }
+}
+
+impl From<DoubleUint> for i128 {
+    fn from(val: DoubleUint) -> Self {
+        val.0 as i128
+    }
+}
+
+impl Borrow<u64> for DoubleUint {
+    /// Borrows the inner value, allowing collections keyed by `DoubleUint` to be queried using
+    /// `u64`s.
+    fn borrow(&self) -> &u64 {
+        &self.0
+    }
+}
+
+impl<'de> Deserialize<'de> for DoubleUint {
+    /// Deserializes a double-uint from an integer.
+    ///
+    /// Numeric strings are also accepted whenever a format provides a string in place of an
+    /// integer, such as for map keys. As with [`DoubleInt`], whether string values are accepted
+    /// depends on the format.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_u64(DoubleUintVisitor)
+    }
+}
+
+impl Serialize for DoubleUint {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_u64(self.0)
+    }
+}
@@ -0,0 +1,106 @@
+use core::fmt;
+
+/// Error returned from fallible [`DoubleInt`](crate::DoubleInt) operations.
+///
+/// # Examples
+///
+/// ```
+/// # use double_int::{DoubleInt, DoubleIntError};
+/// assert_eq!(
+///     DoubleInt::try_from(9_007_199_254_740_992_i64).unwrap_err(),
+///     DoubleIntError::TooLarge(9_007_199_254_740_992),
+/// );
+/// assert_eq!(
+///     DoubleInt::try_from(i64::MIN).unwrap_err(),
+///     DoubleIntError::TooSmall(i64::MIN as i128),
+/// );
+///
+/// assert_eq!(
+///     DoubleIntError::TooLarge(9_007_199_254_740_992).to_string(),
+///     "value 9007199254740992 is larger than 9007199254740991 / (2^53) - 1",
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[non_exhaustive]
+pub enum DoubleIntError {
+    /// Value is larger than 9007199254740991 / (2^53) - 1.
+    ///
+    /// Values larger than `u128::MAX` are saturated.
+    TooLarge(u128),
+
+    /// Value is smaller than -9007199254740991 / -(2^53) + 1.
+    ///
+    /// Values smaller than `i128::MIN` are saturated.
+    TooSmall(i128),
+
+    /// Float value has a fractional part.
+    NotIntegral(f64),
+
+    /// Float value is NaN or infinite.
+    NotFinite(f64),
+
+    /// String contains a character that is not a valid digit.
+    InvalidDigit,
+
+    /// String is empty or contains only a sign.
+    Empty,
+
+    /// Value is negative but an unsigned type was requested.
+    ///
+    /// Values smaller than `i128::MIN` are saturated.
+    Negative(i128),
+
+    /// Value is zero but a non-zero type was requested.
+    Zero,
+
+    /// Value is outside the range `min..=max` of a bounded type.
+    ///
+    /// Values outside the range of `i128` are saturated.
+    OutOfRange {
+        /// The rejected value.
+        value: i128,
+
+        /// The smallest accepted value.
+        min: i64,
+
+        /// The largest accepted value.
+        max: i64,
+    },
+}
+
+impl fmt::Display for DoubleIntError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DoubleIntError::TooLarge(val) => write!(
+                f,
+                "value {} is larger than 9007199254740991 / (2^53) - 1",
+                val,
+            ),
+
+            DoubleIntError::TooSmall(val) => write!(
+                f,
+                "value {} is smaller than -9007199254740991 / -(2^53) + 1",
+                val,
+            ),
+
+            DoubleIntError::NotIntegral(val) => write!(f, "float {} is not an integer", val),
+
+            DoubleIntError::NotFinite(val) => write!(f, "float {} is not finite", val),
+
+            DoubleIntError::InvalidDigit => f.write_str("invalid digit found in string"),
+
+            DoubleIntError::Empty => f.write_str("cannot parse integer from empty string"),
+
+            DoubleIntError::Negative(val) => write!(f, "value {} is negative", val),
+
+            DoubleIntError::Zero => f.write_str("value is zero"),
+
+            DoubleIntError::OutOfRange { value, min, max } => {
+                write!(f, "value {} is outside the range {}..={}", value, min, max)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for DoubleIntError {}
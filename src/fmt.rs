@@ -0,0 +1,31 @@
+use core::fmt;
+
+use crate::{DoubleInt, DoubleUint, SingleInt};
+
+macro_rules! fmt_impl {
+    ($ty:ty: $trait:ident) => {
+        impl fmt::$trait for $ty {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt::$trait::fmt(&self.0, f)
+            }
+        }
+    };
+}
+
+fmt_impl!(DoubleInt: Display);
+fmt_impl!(DoubleInt: LowerHex);
+fmt_impl!(DoubleInt: UpperHex);
+fmt_impl!(DoubleInt: Octal);
+fmt_impl!(DoubleInt: Binary);
+
+fmt_impl!(DoubleUint: Display);
+fmt_impl!(DoubleUint: LowerHex);
+fmt_impl!(DoubleUint: UpperHex);
+fmt_impl!(DoubleUint: Octal);
+fmt_impl!(DoubleUint: Binary);
+
+fmt_impl!(SingleInt: Display);
+fmt_impl!(SingleInt: LowerHex);
+fmt_impl!(SingleInt: UpperHex);
+fmt_impl!(SingleInt: Octal);
+fmt_impl!(SingleInt: Binary);
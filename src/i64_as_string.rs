@@ -0,0 +1,162 @@
+use core::fmt;
+
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::{DoubleInt, DoubleIntError, I64OrString};
+
+/// 64-bit integer that always serializes as a string.
+///
+/// This represents OpenAPI fields with `type: string, format: int64`, commonly used for 64-bit
+/// IDs. Deserializes only from numeric strings; see [`I64AsString::deserialize_number_or_string()`]
+/// to also accept integers.
+///
+/// # Examples
+///
+/// ```
+/// # use double_int::{DoubleInt, I64AsString};
+/// assert_eq!(serde_json::to_string(&I64AsString::new(42)).unwrap(), r#""42""#);
+/// assert_eq!(
+///     serde_json::from_str::<I64AsString>(r#""-9223372036854775808""#).unwrap(),
+///     i64::MIN,
+/// );
+///
+/// serde_json::from_str::<I64AsString>("42").unwrap_err();
+/// serde_json::from_str::<I64AsString>(r#""9223372036854775808""#).unwrap_err();
+/// serde_json::from_str::<I64AsString>(r#""4.2""#).unwrap_err();
+///
+/// let id = I64AsString::try_from(u64::MAX >> 1).unwrap();
+/// assert_eq!(id, i64::MAX);
+/// assert!(id > DoubleInt::MAX);
+/// I64AsString::try_from(u64::MAX).unwrap_err();
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct I64AsString(pub(crate) i64);
+
+impl I64AsString {
+    /// Constructs a new `I64AsString`.
+    pub const fn new(val: i64) -> Self {
+        I64AsString(val)
+    }
+
+    /// Returns value as a standard type.
+    pub const fn as_i64(self) -> i64 {
+        self.0
+    }
+
+    /// Returns value as a double-int, if it is within the double-int bounds.
+    pub const fn as_double_int(self) -> Option<DoubleInt> {
+        DoubleInt::new(self.0)
+    }
+
+    /// Converts a signed integer, checking that it lies within the bounds of `i64`.
+    pub(crate) const fn from_i128(val: i128) -> Result<Self, DoubleIntError> {
+        if val < i64::MIN as i128 || val > i64::MAX as i128 {
+            Err(DoubleIntError::OutOfRange {
+                value: val,
+                min: i64::MIN,
+                max: i64::MAX,
+            })
+        } else {
+            Ok(I64AsString(val as i64))
+        }
+    }
+
+    /// Converts an unsigned integer, checking that it lies within the bounds of `i64`.
+    pub(crate) const fn from_u128(val: u128) -> Result<Self, DoubleIntError> {
+        if val > i128::MAX as u128 {
+            I64AsString::from_i128(i128::MAX)
+        } else {
+            I64AsString::from_i128(val as i128)
+        }
+    }
+
+    /// Deserializes from either a numeric string or an integer.
+    ///
+    /// Use with `#[serde(deserialize_with = "double_int::I64AsString::deserialize_number_or_string")]`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use double_int::I64AsString;
+    /// #[derive(Debug, serde::Deserialize)]
+    /// struct User {
+    ///     #[serde(deserialize_with = "I64AsString::deserialize_number_or_string")]
+    ///     id: I64AsString,
+    /// }
+    ///
+    /// let user = serde_json::from_str::<User>(r#"{ "id": "42" }"#).unwrap();
+    /// assert_eq!(user.id, 42);
+    ///
+    /// let user = serde_json::from_str::<User>(r#"{ "id": 42 }"#).unwrap();
+    /// assert_eq!(user.id, 42);
+    /// ```
+    pub fn deserialize_number_or_string<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Self, D::Error> {
+        I64OrString::deserialize(deserializer).map(I64AsString::from)
+    }
+}
+
+impl From<DoubleInt> for I64AsString {
+    fn from(val: DoubleInt) -> Self {
+        I64AsString(val.0)
+    }
+}
+
+impl From<I64OrString> for I64AsString {
+    fn from(val: I64OrString) -> Self {
+        I64AsString(val.as_i64())
+    }
+}
+
+impl From<I64AsString> for I64OrString {
+    fn from(val: I64AsString) -> Self {
+        I64OrString::new(val.0)
+    }
+}
+
+impl From<I64AsString> for i64 {
+    fn from(val: I64AsString) -> Self {
+        val.0
+    }
+}
+
+impl TryFrom<I64AsString> for DoubleInt {
+    type Error = DoubleIntError;
+
+    fn try_from(val: I64AsString) -> Result<Self, Self::Error> {
+        DoubleInt::try_new(val.0)
+    }
+}
+
+impl fmt::Display for I64AsString {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.0, f)
+    }
+}
+
+impl<'de> Deserialize<'de> for I64AsString {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_str(I64AsStringVisitor)
+    }
+}
+
+impl Serialize for I64AsString {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(&self.0)
+    }
+}
+
+struct I64AsStringVisitor;
+
+impl<'de> de::Visitor<'de> for I64AsStringVisitor {
+    type Value = I64AsString;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a string containing a 64-bit integer")
+    }
+
+    fn visit_str<E: de::Error>(self, val: &str) -> Result<Self::Value, E> {
+        val.parse().map_err(E::custom)
+    }
+}
@@ -0,0 +1,154 @@
+use core::fmt;
+
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::{DoubleInt, DoubleIntError, I64AsString};
+
+/// 64-bit integer that serializes as a number when it is a double-int and as a string otherwise.
+///
+/// This follows the approach of the [protobuf JSON mapping][proto_json] and ensures that
+/// JavaScript consumers never see a silently rounded number. Deserializes from either integers or
+/// numeric strings, regardless of magnitude.
+///
+/// Since deserialization relies on [`Deserializer::deserialize_any()`] and the serialized type
+/// depends on the value, it is only supported by self-describing formats.
+///
+/// # Examples
+///
+/// ```
+/// # use double_int::{DoubleInt, I64OrString};
+/// assert_eq!(serde_json::to_string(&I64OrString::new(42)).unwrap(), "42");
+/// assert_eq!(
+///     serde_json::to_string(&I64OrString::new(9_007_199_254_740_992)).unwrap(),
+///     r#""9007199254740992""#,
+/// );
+///
+/// assert_eq!(serde_json::from_str::<I64OrString>("42").unwrap(), 42);
+/// assert_eq!(serde_json::from_str::<I64OrString>(r#""42""#).unwrap(), 42);
+/// assert_eq!(
+///     serde_json::from_str::<I64OrString>("9223372036854775807").unwrap(),
+///     i64::MAX,
+/// );
+///
+/// serde_json::from_str::<I64OrString>("9223372036854775808").unwrap_err();
+/// serde_json::from_str::<I64OrString>("4.2").unwrap_err();
+///
+/// assert!(I64OrString::new(42) < 43_i64);
+/// assert!(DoubleInt::MAX < I64OrString::new(i64::MAX));
+/// ```
+///
+/// [proto_json]: https://protobuf.dev/programming-guides/json/
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct I64OrString(pub(crate) i64);
+
+impl I64OrString {
+    /// Constructs a new `I64OrString`.
+    pub const fn new(val: i64) -> Self {
+        I64OrString(val)
+    }
+
+    /// Returns value as a standard type.
+    pub const fn as_i64(self) -> i64 {
+        self.0
+    }
+
+    /// Returns value as a double-int, if it is within the double-int bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use double_int::I64OrString;
+    /// assert_eq!(I64OrString::new(42).as_double_int().unwrap(), 42);
+    /// assert!(I64OrString::new(i64::MAX).as_double_int().is_none());
+    /// ```
+    pub const fn as_double_int(self) -> Option<DoubleInt> {
+        DoubleInt::new(self.0)
+    }
+
+    /// Converts a signed integer, checking that it lies within the bounds of `i64`.
+    pub(crate) const fn from_i128(val: i128) -> Result<Self, DoubleIntError> {
+        match I64AsString::from_i128(val) {
+            Ok(val) => Ok(I64OrString(val.0)),
+            Err(err) => Err(err),
+        }
+    }
+
+    /// Converts an unsigned integer, checking that it lies within the bounds of `i64`.
+    pub(crate) const fn from_u128(val: u128) -> Result<Self, DoubleIntError> {
+        match I64AsString::from_u128(val) {
+            Ok(val) => Ok(I64OrString(val.0)),
+            Err(err) => Err(err),
+        }
+    }
+}
+
+impl From<DoubleInt> for I64OrString {
+    fn from(val: DoubleInt) -> Self {
+        I64OrString(val.0)
+    }
+}
+
+impl From<I64OrString> for i64 {
+    fn from(val: I64OrString) -> Self {
+        val.0
+    }
+}
+
+impl TryFrom<I64OrString> for DoubleInt {
+    type Error = DoubleIntError;
+
+    fn try_from(val: I64OrString) -> Result<Self, Self::Error> {
+        DoubleInt::try_new(val.0)
+    }
+}
+
+impl fmt::Display for I64OrString {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.0, f)
+    }
+}
+
+impl<'de> Deserialize<'de> for I64OrString {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_any(I64OrStringVisitor)
+    }
+}
+
+impl Serialize for I64OrString {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self.as_double_int() {
+            Some(val) => val.serialize(serializer),
+            None => serializer.collect_str(&self.0),
+        }
+    }
+}
+
+struct I64OrStringVisitor;
+
+impl<'de> de::Visitor<'de> for I64OrStringVisitor {
+    type Value = I64OrString;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("a 64-bit integer or numeric string")
+    }
+
+    fn visit_i64<E: de::Error>(self, val: i64) -> Result<Self::Value, E> {
+        Ok(I64OrString(val))
+    }
+
+    fn visit_i128<E: de::Error>(self, val: i128) -> Result<Self::Value, E> {
+        I64OrString::from_i128(val).map_err(E::custom)
+    }
+
+    fn visit_u64<E: de::Error>(self, val: u64) -> Result<Self::Value, E> {
+        I64OrString::from_u128(val as u128).map_err(E::custom)
+    }
+
+    fn visit_u128<E: de::Error>(self, val: u128) -> Result<Self::Value, E> {
+        I64OrString::from_u128(val).map_err(E::custom)
+    }
+
+    fn visit_str<E: de::Error>(self, val: &str) -> Result<Self::Value, E> {
+        val.parse().map_err(E::custom)
+    }
+}
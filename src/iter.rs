@@ -0,0 +1,218 @@
+use core::{
+    borrow::Borrow,
+    fmt,
+    iter::{Product, Sum},
+};
+
+use crate::{DoubleInt, DoubleIntError, DoubleUint};
+
+impl Sum for DoubleInt {
+    /// Sums double-ints, panicking if the running total is outside the double-int bounds.
+    ///
+    /// See [`DoubleIntIterExt::checked_sum()`] for a non-panicking alternative.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use double_int::DoubleInt;
+    /// let counts = vec![DoubleInt::from(1), DoubleInt::from(2), DoubleInt::from(3)];
+    /// assert_eq!(counts.iter().sum::<DoubleInt>(), 6);
+    /// assert_eq!(counts.into_iter().product::<DoubleInt>(), 6);
+    /// ```
+    #[track_caller]
+    fn sum<I: Iterator<Item = DoubleInt>>(iter: I) -> Self {
+        let mut total = DoubleInt(0);
+        for val in iter {
+            total += val;
+        }
+        total
+    }
+}
+
+impl<'a> Sum<&'a DoubleInt> for DoubleInt {
+    /// Sums double-ints, panicking if the running total is outside the double-int bounds.
+    ///
+    /// See [`DoubleIntIterExt::checked_sum()`] for a non-panicking alternative.
+    #[track_caller]
+    fn sum<I: Iterator<Item = &'a DoubleInt>>(iter: I) -> Self {
+        let mut total = DoubleInt(0);
+        for val in iter {
+            total += val;
+        }
+        total
+    }
+}
+
+impl Product for DoubleInt {
+    /// Multiplies double-ints, panicking if the running total is outside the double-int bounds.
+    #[track_caller]
+    fn product<I: Iterator<Item = DoubleInt>>(iter: I) -> Self {
+        let mut product = DoubleInt(1);
+        for val in iter {
+            product *= val;
+        }
+        product
+    }
+}
+
+impl<'a> Product<&'a DoubleInt> for DoubleInt {
+    /// Multiplies double-ints, panicking if the running total is outside the double-int bounds.
+    #[track_caller]
+    fn product<I: Iterator<Item = &'a DoubleInt>>(iter: I) -> Self {
+        let mut product = DoubleInt(1);
+        for val in iter {
+            product *= val;
+        }
+        product
+    }
+}
+
+impl Sum for DoubleUint {
+    /// Sums double-uints, panicking if the running total is outside the double-uint bounds.
+    #[track_caller]
+    fn sum<I: Iterator<Item = DoubleUint>>(iter: I) -> Self {
+        let mut total = DoubleUint(0);
+        for val in iter {
+            total += val;
+        }
+        total
+    }
+}
+
+impl<'a> Sum<&'a DoubleUint> for DoubleUint {
+    /// Sums double-uints, panicking if the running total is outside the double-uint bounds.
+    #[track_caller]
+    fn sum<I: Iterator<Item = &'a DoubleUint>>(iter: I) -> Self {
+        let mut total = DoubleUint(0);
+        for val in iter {
+            total += val;
+        }
+        total
+    }
+}
+
+impl Product for DoubleUint {
+    /// Multiplies double-uints, panicking if the running total is outside the double-uint bounds.
+    #[track_caller]
+    fn product<I: Iterator<Item = DoubleUint>>(iter: I) -> Self {
+        let mut product = DoubleUint(1);
+        for val in iter {
+            product *= val;
+        }
+        product
+    }
+}
+
+impl<'a> Product<&'a DoubleUint> for DoubleUint {
+    /// Multiplies double-uints, panicking if the running total is outside the double-uint bounds.
+    #[track_caller]
+    fn product<I: Iterator<Item = &'a DoubleUint>>(iter: I) -> Self {
+        let mut product = DoubleUint(1);
+        for val in iter {
+            product *= val;
+        }
+        product
+    }
+}
+
+/// Error returned from [`DoubleIntIterExt::checked_sum()`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SumError {
+    index: usize,
+    error: DoubleIntError,
+}
+
+impl SumError {
+    /// Returns index of the item at which the running total left the bounds.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Returns error describing the out-of-bounds running total.
+    pub fn error(&self) -> DoubleIntError {
+        self.error
+    }
+}
+
+impl fmt::Display for SumError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "sum overflowed at index {}: {}", self.index, self.error)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for SumError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        Some(&self.error)
+    }
+}
+
+/// Extension methods for iterators of double-ints or double-uints.
+///
+/// `T` is the element type, either [`DoubleInt`] or [`DoubleUint`], and is inferred from the
+/// iterator's items.
+pub trait DoubleIntIterExt<T>: Iterator + Sized
+where
+    Self::Item: Borrow<T>,
+{
+    /// Sums values, returning an error if the running total leaves the bounds of `T`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use double_int::{DoubleInt, DoubleIntError, DoubleIntIterExt as _, DoubleUint};
+    /// let counts = [DoubleInt::from(1), DoubleInt::from(2), DoubleInt::from(3)];
+    /// assert_eq!(counts.iter().checked_sum().unwrap(), 6);
+    ///
+    /// let counts = [DoubleInt::from(1), DoubleInt::MAX, DoubleInt::from(-1)];
+    /// let err = counts.iter().checked_sum().unwrap_err();
+    /// assert_eq!(err.index(), 1);
+    /// assert_eq!(err.error(), DoubleIntError::TooLarge(9_007_199_254_740_992));
+    ///
+    /// let sizes = [DoubleUint::MAX, DoubleUint::from(1_u8)];
+    /// assert_eq!(sizes.iter().checked_sum().unwrap_err().index(), 1);
+    /// ```
+    fn checked_sum(self) -> Result<T, SumError>;
+
+    /// Sums values, clamping the running total to the bounds of `T` after each item.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use double_int::{DoubleInt, DoubleIntIterExt as _, DoubleUint};
+    /// let counts = [DoubleInt::from(1), DoubleInt::MAX, DoubleInt::from(-1)];
+    /// assert_eq!(counts.iter().saturating_sum(), DoubleInt::MAX - 1);
+    ///
+    /// let sizes = vec![DoubleUint::MAX, DoubleUint::from(1_u8)];
+    /// assert_eq!(sizes.into_iter().saturating_sum(), DoubleUint::MAX);
+    /// ```
+    fn saturating_sum(self) -> T;
+}
+
+macro_rules! iter_ext_impl {
+    ($ty:ident) => {
+        impl<I> DoubleIntIterExt<$ty> for I
+        where
+            I: Iterator,
+            I::Item: Borrow<$ty>,
+        {
+            fn checked_sum(self) -> Result<$ty, SumError> {
+                let mut total = $ty(0);
+
+                for (index, val) in self.enumerate() {
+                    let sum = total.0 as i128 + val.borrow().0 as i128;
+                    total = $ty::from_i128(sum).map_err(|error| SumError { index, error })?;
+                }
+
+                Ok(total)
+            }
+
+            fn saturating_sum(self) -> $ty {
+                self.fold($ty(0), |acc, val| acc.saturating_add(*val.borrow()))
+            }
+        }
+    };
+}
+
+iter_ext_impl!(DoubleInt);
+iter_ext_impl!(DoubleUint);
@@ -0,0 +1,93 @@
+//! Lenient (de)serialization of [`DoubleInt`]s, [`DoubleUint`]s and [`SingleInt`]s that also
+//! accepts integral floats.
+//!
+//! JavaScript clients routinely emit integral values as floats (e.g., after `Math.round`), which
+//! some formats encode as `42.0` or `1e3`. This module accepts those values as long as they are
+//! finite, integral, and within the bounds of the field's type. Serialization is unchanged.
+//!
+//! Use with `#[serde(with = "double_int::lenient")]`. Since this mode relies on
+//! [`Deserializer::deserialize_any()`], it is only supported by self-describing formats.
+//!
+//! # Examples
+//!
+//! ```
+//! # use double_int::{DoubleInt, DoubleUint};
+//! #[derive(Debug, serde::Deserialize)]
+//! struct Config {
+//!     #[serde(with = "double_int::lenient")]
+//!     count: DoubleInt,
+//!
+//!     #[serde(default, with = "double_int::lenient")]
+//!     retries: DoubleUint,
+//! }
+//!
+//! let config = serde_json::from_str::<Config>(r#"{ "count": 42 }"#).unwrap();
+//! assert_eq!(config.count, 42);
+//!
+//! let config = serde_json::from_str::<Config>(r#"{ "count": 42.0 }"#).unwrap();
+//! assert_eq!(config.count, 42);
+//!
+//! let config = serde_json::from_str::<Config>(r#"{ "count": 1e3 }"#).unwrap();
+//! assert_eq!(config.count, 1000);
+//!
+//! serde_json::from_str::<Config>(r#"{ "count": 4.2 }"#).unwrap_err();
+//! serde_json::from_str::<Config>(r#"{ "count": 1e300 }"#).unwrap_err();
+//!
+//! let config = serde_json::from_str::<Config>(r#"{ "count": 0, "retries": 3.0 }"#).unwrap();
+//! assert_eq!(config.retries, 3_u64);
+//! serde_json::from_str::<Config>(r#"{ "count": 0, "retries": -3.0 }"#).unwrap_err();
+//! ```
+//!
+//! [`DoubleInt`]: crate::DoubleInt
+//! [`DoubleUint`]: crate::DoubleUint
+//! [`SingleInt`]: crate::SingleInt
+
+use core::{fmt, marker::PhantomData};
+
+use serde::{de, Deserializer, Serializer};
+
+use crate::conv::Integer;
+
+/// Deserializes a double-int, double-uint or single-int from an integer or integral float.
+pub fn deserialize<'de, T: Integer, D: Deserializer<'de>>(deserializer: D) -> Result<T, D::Error> {
+    deserializer.deserialize_any(LenientVisitor(PhantomData))
+}
+
+/// Serializes a double-int, double-uint or single-int as an integer.
+pub fn serialize<T: Integer, S: Serializer>(val: &T, serializer: S) -> Result<S::Ok, S::Error> {
+    val.serialize(serializer)
+}
+
+struct LenientVisitor<T>(PhantomData<T>);
+
+impl<'de, T: Integer> de::Visitor<'de> for LenientVisitor<T> {
+    type Value = T;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "an integer or integral float within the {} bounds",
+            T::NAME
+        )
+    }
+
+    fn visit_i64<E: de::Error>(self, val: i64) -> Result<Self::Value, E> {
+        T::from_i128(val as i128).map_err(E::custom)
+    }
+
+    fn visit_i128<E: de::Error>(self, val: i128) -> Result<Self::Value, E> {
+        T::from_i128(val).map_err(E::custom)
+    }
+
+    fn visit_u64<E: de::Error>(self, val: u64) -> Result<Self::Value, E> {
+        T::from_u128(val as u128).map_err(E::custom)
+    }
+
+    fn visit_u128<E: de::Error>(self, val: u128) -> Result<Self::Value, E> {
+        T::from_u128(val).map_err(E::custom)
+    }
+
+    fn visit_f64<E: de::Error>(self, val: f64) -> Result<Self::Value, E> {
+        T::try_from(val).map_err(E::custom)
+    }
+}
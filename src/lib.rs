@@ -30,15 +30,73 @@
 //! "#).unwrap_err();
 //! ```
 //!
+//! # Crate features
+//!
+//! - `std`: implements `std::error::Error` for error types.
+//! - `atomic`: adds `AtomicDoubleInt`. Requires a target with 64-bit atomics, which is why it is
+//!   not enabled by default.
+//!
 //! [reg_double_int]: https://spec.openapis.org/registry/format/double-int
 
 #![no_std]
 #![deny(rust_2018_idioms, nonstandard_style, future_incompatible)]
 #![cfg_attr(docsrs, feature(doc_auto_cfg))]
 
+#[cfg(feature = "std")]
+extern crate std;
+
+use core::borrow::Borrow;
+
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
-/// Type that only deserializes from the `true` boolean value.
+mod arith;
+#[cfg(feature = "atomic")]
+mod atomic;
+mod bounded;
+mod conv;
+mod double_uint;
+mod error;
+mod fmt;
+mod i64_as_string;
+mod i64_or_string;
+mod iter;
+mod macros;
+mod nonzero;
+mod ops;
+mod parse;
+mod range;
+mod rounding;
+mod safe;
+mod seed;
+mod single_int;
+
+pub mod lenient;
+pub mod number_or_string;
+pub mod string_keys;
+
+#[cfg(feature = "atomic")]
+pub use self::atomic::AtomicDoubleInt;
+pub use self::{
+    bounded::BoundedDoubleInt,
+    double_uint::DoubleUint,
+    error::DoubleIntError,
+    i64_as_string::I64AsString,
+    i64_or_string::I64OrString,
+    iter::{DoubleIntIterExt, SumError},
+    nonzero::{NonZeroDoubleInt, NonZeroDoubleUint},
+    range::DoubleIntRange,
+    rounding::RoundingMode,
+    safe::SafeInteger,
+    seed::DoubleIntSeed,
+    single_int::SingleInt,
+};
+
+#[doc(hidden)]
+pub mod __private {
+    pub use crate::macros::new_or_fail;
+}
+
+/// Integer that can be stored in an IEEE 754 double-precision number without loss of precision.
 ///
 /// # Examples
 ///
@@ -50,117 +108,263 @@ use serde::{Deserialize, Deserializer, Serialize, Serializer};
 ///
 /// serde_json::from_str::<double_int::DoubleInt>("4.2").unwrap_err();
 /// serde_json::from_str::<double_int::DoubleInt>("36028797018963968").unwrap_err();
+///
+/// assert_eq!(
+///     serde_json::from_str::<double_int::DoubleInt>("-9007199254740992")
+///         .unwrap_err()
+///         .to_string(),
+///     "value -9007199254740992 is smaller than -9007199254740991 / -(2^53) + 1 at line 1 column 17",
+/// );
+///
+/// let count = double_int::DoubleInt::from(255);
+/// assert_eq!(count.to_string(), "255");
+/// assert_eq!(format!("{:#x} {:o} {:b}", count, count, count), "0xff 377 11111111");
+/// ```
+///
+/// # Arithmetic
+///
+/// Arithmetic operators are implemented between double-ints and with primitive integers that
+/// convert infallibly into double-ints. Operators panic if the result is outside the double-int
+/// bounds, regardless of build profile. See [`DoubleInt::checked_add()`] and friends for
+/// non-panicking alternatives.
+///
+/// ```
+/// # use double_int::DoubleInt;
+/// let mut total = DoubleInt::from(40);
+/// total += 2;
+/// assert_eq!(total * DoubleInt::from(2) - 4, 80);
+/// assert_eq!(-total % 5_u8, -2);
+/// ```
+///
+/// ```should_panic
+/// # use double_int::DoubleInt;
+/// let _ = DoubleInt::MAX + 1;
 /// ```
-#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+///
+/// # Layout
+///
+/// `DoubleInt` stores its value as a plain `i64`, so `Option<DoubleInt>` is 16 bytes. A biased
+/// encoding in a `NonZeroU64` would make it 8 bytes, but could not support the
+/// [`Borrow<i64>`](Borrow) impl, which needs a stored `i64` to hand out references to. Use
+/// [`NonZeroDoubleInt`] where values are never zero to keep `Option`s 8 bytes.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct DoubleInt(i64);
 
 impl DoubleInt {
-    const MIN: i128 = -(2_i128.pow(53)) + 1;
-    const MAX: i128 = 2_i128.pow(53) - 1;
-    const UMAX: u128 = 2_u128.pow(53) - 1;
+    /// The smallest value that can be represented by this type, -(2^53) + 1.
+    pub const MIN: DoubleInt = DoubleInt(-(2_i64.pow(53)) + 1);
+
+    /// The largest value that can be represented by this type, (2^53) - 1.
+    pub const MAX: DoubleInt = DoubleInt(2_i64.pow(53) - 1);
+
+    const MIN_I128: i128 = DoubleInt::MIN.0 as i128;
+    const MAX_I128: i128 = DoubleInt::MAX.0 as i128;
+    const MAX_U128: u128 = DoubleInt::MAX.0 as u128;
+
+    /// Constructs a new double-int, returning `None` if `val` is outside the double-int bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use double_int::DoubleInt;
+    /// const COUNT: Option<DoubleInt> = DoubleInt::new(42);
+    /// assert_eq!(COUNT.unwrap(), 42);
+    ///
+    /// assert!(DoubleInt::new(i64::MAX).is_none());
+    /// ```
+    pub const fn new(val: i64) -> Option<Self> {
+        match DoubleInt::try_new(val) {
+            Ok(val) => Some(val),
+            Err(_) => None,
+        }
+    }
+
+    /// Constructs a new double-int, returning an error if `val` is outside the double-int bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use double_int::{DoubleInt, DoubleIntError};
+    /// assert_eq!(DoubleInt::try_new(42).unwrap(), 42);
+    ///
+    /// assert_eq!(
+    ///     DoubleInt::try_new(i64::MAX).unwrap_err(),
+    ///     DoubleIntError::TooLarge(i64::MAX as u128),
+    /// );
+    /// ```
+    pub const fn try_new(val: i64) -> Result<Self, DoubleIntError> {
+        DoubleInt::from_i128(val as i128)
+    }
+
+    /// Constructs a new double-int without checking that `val` is within the double-int bounds.
+    ///
+    /// # Safety
+    ///
+    /// `val` must be within the range [`DoubleInt::MIN`]..=[`DoubleInt::MAX`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use double_int::DoubleInt;
+    /// // SAFETY: 42 is within double-int bounds
+    /// const COUNT: DoubleInt = unsafe { DoubleInt::new_unchecked(42) };
+    /// assert_eq!(COUNT, 42);
+    /// ```
+    pub const unsafe fn new_unchecked(val: i64) -> Self {
+        DoubleInt(val)
+    }
 
     /// Returns value as a standard type.
     pub const fn as_i64(self) -> i64 {
         self.0
     }
-}
 
-macro_rules! from_impl {
-    ($ty:ty) => {
-        impl From<$ty> for DoubleInt {
-            fn from(val: $ty) -> Self {
-                Self(val as i64)
-            }
+    /// Converts a signed integer, checking that it lies within the double-int bounds.
+    const fn from_i128(val: i128) -> Result<Self, DoubleIntError> {
+        if val > DoubleInt::MAX_I128 {
+            Err(DoubleIntError::TooLarge(val as u128))
+        } else if val < DoubleInt::MIN_I128 {
+            Err(DoubleIntError::TooSmall(val))
+        } else {
+            Ok(DoubleInt(val as i64))
         }
-    };
-}
+    }
 
-from_impl!(u8);
-from_impl!(u16);
-from_impl!(u32);
-from_impl!(i8);
-from_impl!(i16);
-from_impl!(i32);
-
-macro_rules! infallible_eq_impls {
-    ($ty:ty) => {
-        impl PartialEq<$ty> for DoubleInt {
-            fn eq(&self, val: &$ty) -> bool {
-                self.0 == *val as i64
-            }
+    /// Converts an unsigned integer, checking that it lies within the double-int bounds.
+    const fn from_u128(val: u128) -> Result<Self, DoubleIntError> {
+        if val > DoubleInt::MAX_U128 {
+            Err(DoubleIntError::TooLarge(val))
+        } else {
+            Ok(DoubleInt(val as i64))
         }
-    };
-}
+    }
 
-infallible_eq_impls!(u8);
-infallible_eq_impls!(u16);
-infallible_eq_impls!(u32);
-infallible_eq_impls!(i8);
-infallible_eq_impls!(i16);
-infallible_eq_impls!(i32);
-
-impl PartialEq<u64> for DoubleInt {
-    fn eq(&self, val: &u64) -> bool {
-        match *val as u128 {
-            // self cannot be larger than UMAX so val is not equal
-            DoubleInt::UMAX.. => false,
-
-            // all remaining u64s would be representable by i64
-            // just cast and check equality
-            _ => self.0 == *val as i64,
+    /// Converts a float, checking that it is a finite integer within the double-int bounds.
+    fn from_f64(val: f64) -> Result<Self, DoubleIntError> {
+        if !val.is_finite() {
+            Err(DoubleIntError::NotFinite(val))
+        } else if val > DoubleInt::MAX.0 as f64 {
+            Err(DoubleIntError::TooLarge(val as u128))
+        } else if val < DoubleInt::MIN.0 as f64 {
+            Err(DoubleIntError::TooSmall(val as i128))
+        } else if (val as i64) as f64 != val {
+            // all floats within bounds are exactly representable by i64
+            // so a lossy round-trip means there was a fractional part
+            Err(DoubleIntError::NotIntegral(val))
+        } else {
+            Ok(DoubleInt(val as i64))
         }
     }
 }
 
-impl PartialEq<u128> for DoubleInt {
-    fn eq(&self, val: &u128) -> bool {
-        match val {
-            // self cannot be larger than UMAX so val is not equal
-            DoubleInt::UMAX.. => false,
+impl TryFrom<f64> for DoubleInt {
+    type Error = DoubleIntError;
+
+    /// Converts a float into a double-int, failing if the conversion would lose precision.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use double_int::{DoubleInt, DoubleIntError};
+    /// assert_eq!(DoubleInt::try_from(42.0_f64).unwrap(), 42);
+    /// assert_eq!(DoubleInt::try_from(-0.0_f64).unwrap(), 0);
+    ///
+    /// assert!(matches!(DoubleInt::try_from(4.2_f64), Err(DoubleIntError::NotIntegral(_))));
+    /// assert!(matches!(DoubleInt::try_from(f64::NAN), Err(DoubleIntError::NotFinite(_))));
+    /// assert!(matches!(DoubleInt::try_from(f64::INFINITY), Err(DoubleIntError::NotFinite(_))));
+    /// assert!(matches!(DoubleInt::try_from(9007199254740992.0_f64), Err(DoubleIntError::TooLarge(_))));
+    /// assert!(matches!(DoubleInt::try_from(-9007199254740992.0_f64), Err(DoubleIntError::TooSmall(_))));
+    /// ```
+    fn try_from(val: f64) -> Result<Self, Self::Error> {
+        DoubleInt::from_f64(val)
+    }
+}
 
-            // all remaining u64s would be representable by i64
-            // just cast and check equality
-            _ => self.0 == *val as i64,
-        }
+impl TryFrom<f32> for DoubleInt {
+    type Error = DoubleIntError;
+
+    /// Converts a float into a double-int, failing if the conversion would lose precision.
+    fn try_from(val: f32) -> Result<Self, Self::Error> {
+        DoubleInt::from_f64(f64::from(val))
     }
 }
 
-impl PartialEq<i64> for DoubleInt {
-    fn eq(&self, val: &i64) -> bool {
-        self.0 == *val
+impl From<DoubleInt> for f64 {
+    fn from(val: DoubleInt) -> Self {
+        // all double-ints are exactly representable by f64
+        val.0 as f64
     }
 }
 
-impl PartialEq<i128> for DoubleInt {
-    fn eq(&self, val: &i128) -> bool {
-        match val {
-            // self cannot be larger than UMAX so val is not equal
-            DoubleInt::MAX.. => false,
+impl From<DoubleInt> for i64 {
+    fn from(val: DoubleInt) -> Self {
+        val.0
+    }
+}
 
-            // all remaining u64s would be representable by i64
-            // just cast and check equality
-            _ => self.0 == *val as i64,
-        }
+impl Borrow<i64> for DoubleInt {
+    /// Borrows the inner value, allowing collections keyed by `DoubleInt` to be queried using
+    /// `i64`s.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::collections::{BTreeMap, HashSet};
+    /// # use double_int::DoubleInt;
+    /// let ids = HashSet::from([DoubleInt::from(1), DoubleInt::from(2)]);
+    /// assert!(ids.contains(&2_i64));
+    ///
+    /// let mut names = BTreeMap::new();
+    /// names.insert(DoubleInt::from(42), "foo");
+    /// assert_eq!(names.get(&42_i64), Some(&"foo"));
+    /// ```
+    fn borrow(&self) -> &i64 {
+        &self.0
+    }
+}
+
+impl From<DoubleInt> for i128 {
+    fn from(val: DoubleInt) -> Self {
+        val.0 as i128
     }
 }
 
 impl<'de> Deserialize<'de> for DoubleInt {
+    /// Deserializes a double-int from an integer.
+    ///
+    /// Numeric strings are also accepted, with the same bounds checks, whenever a format provides a
+    /// string in place of an integer. This lets maps keyed by double-ints be deserialized from
+    /// string-keyed formats such as TOML. Whether string _values_ are accepted depends on the
+    /// format:
+    ///
+    /// - Strictly typed formats, such as JSON and YAML, reject string values like `"42"`.
+    /// - Formats that forward integer requests to [`Deserializer::deserialize_any()`], such as
+    ///   TOML, accept them.
+    ///
+    /// Use [`number_or_string`] to accept numeric string values in every self-describing format,
+    /// and [`string_keys`] to also serialize keys as strings.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use std::collections::BTreeMap;
+    /// # use double_int::DoubleInt;
+    /// let names = serde_json::from_str::<BTreeMap<DoubleInt, String>>(r#"{ "42": "foo" }"#).unwrap();
+    /// assert_eq!(names[&42], "foo");
+    ///
+    /// serde_json::from_str::<BTreeMap<DoubleInt, String>>(r#"{ "36028797018963968": "foo" }"#)
+    ///     .unwrap_err();
+    ///
+    /// let names = toml::from_str::<BTreeMap<DoubleInt, String>>(r#"42 = "foo""#).unwrap();
+    /// assert_eq!(names[&42], "foo");
+    ///
+    /// // string values are rejected by strictly typed formats but accepted by TOML
+    /// serde_json::from_str::<DoubleInt>(r#""42""#).unwrap_err();
+    /// let counts = toml::from_str::<BTreeMap<String, DoubleInt>>(r#"count = "42""#).unwrap();
+    /// assert_eq!(counts["count"], 42);
+    /// ```
     fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
-        match i64::deserialize(deserializer) {
-            Err(err) => Err(err),
-
-            Ok(val) if (val as i128) < DoubleInt::MIN => Err(serde::de::Error::invalid_value(
-                serde::de::Unexpected::Signed(val),
-                &"integer larger than -9007199254740991 / -(2^53) + 1",
-            )),
-
-            Ok(val) if (val as i128) > DoubleInt::MAX => Err(serde::de::Error::invalid_value(
-                serde::de::Unexpected::Signed(val),
-                &"integer smaller than 9007199254740991 / (2^53) - 1",
-            )),
-
-            Ok(val) => Ok(DoubleInt(val)),
-        }
+        deserializer.deserialize_i64(conv::DoubleIntVisitor)
     }
 }
 
@@ -0,0 +1,42 @@
+use crate::DoubleInt;
+
+/// Constructs a [`DoubleInt`](crate::DoubleInt) from a constant, checking bounds at compile time.
+///
+/// The resulting value can be used in `const` and `static` items.
+///
+/// # Examples
+///
+/// ```
+/// use double_int::{double_int, DoubleInt};
+///
+/// const MAX_PAGE_SIZE: DoubleInt = double_int!(500);
+/// assert_eq!(MAX_PAGE_SIZE, 500);
+///
+/// let offset = double_int!(-9_007_199_254_740_991);
+/// assert_eq!(offset, DoubleInt::MIN);
+/// ```
+///
+/// Values outside the double-int bounds fail to compile:
+///
+/// ```compile_fail
+/// use double_int::{double_int, DoubleInt};
+///
+/// const TOO_LARGE: DoubleInt = double_int!(9_007_199_254_740_992);
+/// ```
+#[macro_export]
+macro_rules! double_int {
+    ($val:expr) => {{
+        const VAL: $crate::DoubleInt = $crate::__private::new_or_fail($val);
+        VAL
+    }};
+}
+
+/// Constructs a double-int, failing const evaluation if `val` is out of bounds.
+pub const fn new_or_fail(val: i64) -> DoubleInt {
+    let in_bounds = DoubleInt::new(val).is_some();
+
+    // indexing out of bounds is used to fail const evaluation since `panic!` is not usable in
+    // const contexts on our MSRV
+    let value_out_of_double_int_bounds = [DoubleInt(val)];
+    value_out_of_double_int_bounds[!in_bounds as usize]
+}
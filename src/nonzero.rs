@@ -0,0 +1,255 @@
+use core::{
+    cmp::Ordering,
+    fmt,
+    num::{NonZeroI64, NonZeroU64},
+    str::FromStr,
+};
+
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::{DoubleInt, DoubleIntError, DoubleUint};
+
+macro_rules! nonzero_impls {
+    ($ty:ident($nonzero:ty) => $inner:ident($prim:ty), $visitor:ident, $de:ident, $name:literal) => {
+        impl $ty {
+            /// Constructs a new non-zero value, returning `None` if `val` is zero.
+            pub const fn new(val: $inner) -> Option<Self> {
+                match <$nonzero>::new(val.0) {
+                    Some(val) => Some($ty(val)),
+                    None => None,
+                }
+            }
+
+            /// Constructs a new non-zero value without checking that `val` is non-zero.
+            ///
+            /// # Safety
+            ///
+            /// `val` must not be zero.
+            pub const unsafe fn new_unchecked(val: $inner) -> Self {
+                $ty(<$nonzero>::new_unchecked(val.0))
+            }
+
+            #[doc = concat!("Returns value as a ", $name, ".")]
+            pub const fn get(self) -> $inner {
+                $inner(self.0.get())
+            }
+        }
+
+        impl TryFrom<$inner> for $ty {
+            type Error = DoubleIntError;
+
+            fn try_from(val: $inner) -> Result<Self, Self::Error> {
+                $ty::new(val).ok_or(DoubleIntError::Zero)
+            }
+        }
+
+        impl TryFrom<$prim> for $ty {
+            type Error = DoubleIntError;
+
+            fn try_from(val: $prim) -> Result<Self, Self::Error> {
+                $inner::try_from(val).and_then($ty::try_from)
+            }
+        }
+
+        impl TryFrom<$nonzero> for $ty {
+            type Error = DoubleIntError;
+
+            fn try_from(val: $nonzero) -> Result<Self, Self::Error> {
+                $inner::try_from(val.get()).and_then($ty::try_from)
+            }
+        }
+
+        impl From<$ty> for $inner {
+            fn from(val: $ty) -> Self {
+                val.get()
+            }
+        }
+
+        impl From<$ty> for $prim {
+            fn from(val: $ty) -> Self {
+                val.0.get()
+            }
+        }
+
+        impl From<$ty> for $nonzero {
+            fn from(val: $ty) -> Self {
+                val.0
+            }
+        }
+
+        impl From<$ty> for f64 {
+            fn from(val: $ty) -> Self {
+                f64::from(val.get())
+            }
+        }
+
+        impl PartialEq<$inner> for $ty {
+            fn eq(&self, val: &$inner) -> bool {
+                self.0.get() == val.0
+            }
+        }
+
+        impl PartialEq<$ty> for $inner {
+            fn eq(&self, val: &$ty) -> bool {
+                self.0 == val.0.get()
+            }
+        }
+
+        impl PartialOrd<$inner> for $ty {
+            fn partial_cmp(&self, val: &$inner) -> Option<Ordering> {
+                self.0.get().partial_cmp(&val.0)
+            }
+        }
+
+        impl PartialOrd<$ty> for $inner {
+            fn partial_cmp(&self, val: &$ty) -> Option<Ordering> {
+                self.0.partial_cmp(&val.0.get())
+            }
+        }
+
+        impl PartialEq<$prim> for $ty {
+            fn eq(&self, val: &$prim) -> bool {
+                self.0.get() == *val
+            }
+        }
+
+        impl PartialEq<$ty> for $prim {
+            fn eq(&self, val: &$ty) -> bool {
+                *self == val.0.get()
+            }
+        }
+
+        impl PartialOrd<$prim> for $ty {
+            fn partial_cmp(&self, val: &$prim) -> Option<Ordering> {
+                self.0.get().partial_cmp(val)
+            }
+        }
+
+        impl PartialOrd<$ty> for $prim {
+            fn partial_cmp(&self, val: &$ty) -> Option<Ordering> {
+                self.partial_cmp(&val.0.get())
+            }
+        }
+
+        impl fmt::Display for $ty {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt::Display::fmt(&self.0, f)
+            }
+        }
+
+        impl FromStr for $ty {
+            type Err = DoubleIntError;
+
+            fn from_str(src: &str) -> Result<Self, Self::Err> {
+                src.parse::<$inner>().and_then($ty::try_from)
+            }
+        }
+
+        impl<'de> Deserialize<'de> for $ty {
+            fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                deserializer.$de($visitor)
+            }
+        }
+
+        impl Serialize for $ty {
+            fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                self.get().serialize(serializer)
+            }
+        }
+
+        struct $visitor;
+
+        impl<'de> de::Visitor<'de> for $visitor {
+            type Value = $ty;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str(concat!("a non-zero ", $name))
+            }
+
+            fn visit_i64<E: de::Error>(self, val: i64) -> Result<Self::Value, E> {
+                $inner::try_from(val)
+                    .and_then($ty::try_from)
+                    .map_err(E::custom)
+            }
+
+            fn visit_i128<E: de::Error>(self, val: i128) -> Result<Self::Value, E> {
+                $inner::try_from(val)
+                    .and_then($ty::try_from)
+                    .map_err(E::custom)
+            }
+
+            fn visit_u64<E: de::Error>(self, val: u64) -> Result<Self::Value, E> {
+                $inner::try_from(val)
+                    .and_then($ty::try_from)
+                    .map_err(E::custom)
+            }
+
+            fn visit_u128<E: de::Error>(self, val: u128) -> Result<Self::Value, E> {
+                $inner::try_from(val)
+                    .and_then($ty::try_from)
+                    .map_err(E::custom)
+            }
+
+            fn visit_str<E: de::Error>(self, val: &str) -> Result<Self::Value, E> {
+                val.parse().map_err(E::custom)
+            }
+        }
+    };
+}
+
+/// Double-int that is known not to be zero.
+///
+/// Backed by [`NonZeroI64`] so that `Option<NonZeroDoubleInt>` is the same size as
+/// `NonZeroDoubleInt`, which suits identifiers that are never zero. Deserialization rejects `0` in
+/// addition to values outside the double-int bounds.
+///
+/// `Option<DoubleInt>` is 16 bytes since [`DoubleInt`] stores a plain `i64` in order to implement
+/// `Borrow<i64>`.
+///
+/// # Examples
+///
+/// ```
+/// # use core::mem::size_of;
+/// # use double_int::{DoubleInt, DoubleIntError, NonZeroDoubleInt};
+/// assert_eq!(size_of::<Option<NonZeroDoubleInt>>(), 8);
+///
+/// let id = serde_json::from_str::<NonZeroDoubleInt>("-42").unwrap();
+/// assert_eq!(id, -42);
+/// assert_eq!(id.get(), DoubleInt::from(-42));
+///
+/// serde_json::from_str::<NonZeroDoubleInt>("0").unwrap_err();
+/// serde_json::from_str::<NonZeroDoubleInt>("9007199254740992").unwrap_err();
+///
+/// assert_eq!(
+///     NonZeroDoubleInt::try_from(DoubleInt::from(0)).unwrap_err(),
+///     DoubleIntError::Zero,
+/// );
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct NonZeroDoubleInt(NonZeroI64);
+
+/// Double-uint that is known not to be zero.
+///
+/// Backed by [`NonZeroU64`] so that `Option<NonZeroDoubleUint>` is the same size as
+/// `NonZeroDoubleUint`. Deserialization rejects `0` in addition to values outside the double-uint
+/// bounds.
+///
+/// # Examples
+///
+/// ```
+/// # use core::mem::size_of;
+/// # use double_int::{DoubleUint, NonZeroDoubleUint};
+/// assert_eq!(size_of::<Option<NonZeroDoubleUint>>(), 8);
+///
+/// let id = serde_json::from_str::<NonZeroDoubleUint>("42").unwrap();
+/// assert_eq!(id, 42);
+/// assert_eq!(id.get(), DoubleUint::from(42_u8));
+///
+/// serde_json::from_str::<NonZeroDoubleUint>("0").unwrap_err();
+/// serde_json::from_str::<NonZeroDoubleUint>("-42").unwrap_err();
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct NonZeroDoubleUint(NonZeroU64);
+
+nonzero_impls!(NonZeroDoubleInt(NonZeroI64) => DoubleInt(i64), NonZeroDoubleIntVisitor, deserialize_i64, "double-int");
+nonzero_impls!(NonZeroDoubleUint(NonZeroU64) => DoubleUint(u64), NonZeroDoubleUintVisitor, deserialize_u64, "double-uint");
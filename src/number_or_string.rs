@@ -0,0 +1,96 @@
+//! (De)serialization of [`DoubleInt`]s, [`DoubleUint`]s and [`SingleInt`]s that also accepts
+//! numeric strings.
+//!
+//! Query strings, form bodies, environment variables, and CSV files often carry numbers as
+//! strings. This module accepts base 10 strings such as `"42"` or `"-9007199254740991"` in
+//! addition to integers, checking that either is within the bounds of the field's type.
+//! Serialization is unchanged.
+//!
+//! Use with `#[serde(with = "double_int::number_or_string")]`. Since this mode relies on
+//! [`Deserializer::deserialize_any()`], it is only supported by self-describing formats.
+//!
+//! # Examples
+//!
+//! ```
+//! # use double_int::{DoubleInt, DoubleUint};
+//! #[derive(Debug, serde::Deserialize)]
+//! struct Query {
+//!     #[serde(with = "double_int::number_or_string")]
+//!     page: DoubleInt,
+//!
+//!     #[serde(default, with = "double_int::number_or_string")]
+//!     limit: DoubleUint,
+//! }
+//!
+//! let query = serde_json::from_str::<Query>(r#"{ "page": 42 }"#).unwrap();
+//! assert_eq!(query.page, 42);
+//!
+//! let query = serde_json::from_str::<Query>(r#"{ "page": "42" }"#).unwrap();
+//! assert_eq!(query.page, 42);
+//!
+//! let query = serde_urlencoded::from_str::<Query>("page=-9007199254740991").unwrap();
+//! assert_eq!(query.page, DoubleInt::MIN);
+//!
+//! serde_json::from_str::<Query>(r#"{ "page": "4.2" }"#).unwrap_err();
+//! serde_json::from_str::<Query>(r#"{ "page": "9007199254740992" }"#).unwrap_err();
+//! serde_urlencoded::from_str::<Query>("page=").unwrap_err();
+//!
+//! let query = serde_urlencoded::from_str::<Query>("page=1&limit=50").unwrap();
+//! assert_eq!(query.limit, 50_u64);
+//! serde_urlencoded::from_str::<Query>("page=1&limit=-50").unwrap_err();
+//! ```
+//!
+//! [`DoubleInt`]: crate::DoubleInt
+//! [`DoubleUint`]: crate::DoubleUint
+//! [`SingleInt`]: crate::SingleInt
+
+use core::{fmt, marker::PhantomData};
+
+use serde::{de, Deserializer, Serializer};
+
+use crate::conv::Integer;
+
+/// Deserializes a double-int, double-uint or single-int from an integer or numeric string.
+pub fn deserialize<'de, T: Integer, D: Deserializer<'de>>(deserializer: D) -> Result<T, D::Error> {
+    deserializer.deserialize_any(NumberOrStringVisitor(PhantomData))
+}
+
+/// Serializes a double-int, double-uint or single-int as an integer.
+pub fn serialize<T: Integer, S: Serializer>(val: &T, serializer: S) -> Result<S::Ok, S::Error> {
+    val.serialize(serializer)
+}
+
+/// Visitor that accepts integers and numeric strings within the bounds of `T`.
+pub(crate) struct NumberOrStringVisitor<T>(pub(crate) PhantomData<T>);
+
+impl<'de, T: Integer> de::Visitor<'de> for NumberOrStringVisitor<T> {
+    type Value = T;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "an integer or numeric string within the {} bounds",
+            T::NAME
+        )
+    }
+
+    fn visit_i64<E: de::Error>(self, val: i64) -> Result<Self::Value, E> {
+        T::from_i128(val as i128).map_err(E::custom)
+    }
+
+    fn visit_i128<E: de::Error>(self, val: i128) -> Result<Self::Value, E> {
+        T::from_i128(val).map_err(E::custom)
+    }
+
+    fn visit_u64<E: de::Error>(self, val: u64) -> Result<Self::Value, E> {
+        T::from_u128(val as u128).map_err(E::custom)
+    }
+
+    fn visit_u128<E: de::Error>(self, val: u128) -> Result<Self::Value, E> {
+        T::from_u128(val).map_err(E::custom)
+    }
+
+    fn visit_str<E: de::Error>(self, val: &str) -> Result<Self::Value, E> {
+        val.parse().map_err(E::custom)
+    }
+}
@@ -0,0 +1,132 @@
+use core::ops::{
+    Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign,
+};
+
+use crate::{DoubleInt, DoubleUint};
+
+macro_rules! binop_impls {
+    ($ty:ident: $trait:ident, $method:ident, $assign_trait:ident, $assign_method:ident => $checked:ident, $msg:literal) => {
+        impl $trait for $ty {
+            type Output = $ty;
+
+            #[track_caller]
+            fn $method(self, rhs: $ty) -> Self::Output {
+                match self.$checked(rhs) {
+                    Some(val) => val,
+                    None => panic!($msg),
+                }
+            }
+        }
+
+        impl $trait<&$ty> for $ty {
+            type Output = $ty;
+
+            #[track_caller]
+            fn $method(self, rhs: &$ty) -> Self::Output {
+                $trait::$method(self, *rhs)
+            }
+        }
+
+        impl $trait<$ty> for &$ty {
+            type Output = $ty;
+
+            #[track_caller]
+            fn $method(self, rhs: $ty) -> Self::Output {
+                $trait::$method(*self, rhs)
+            }
+        }
+
+        impl $trait<&$ty> for &$ty {
+            type Output = $ty;
+
+            #[track_caller]
+            fn $method(self, rhs: &$ty) -> Self::Output {
+                $trait::$method(*self, *rhs)
+            }
+        }
+
+        impl $assign_trait for $ty {
+            #[track_caller]
+            fn $assign_method(&mut self, rhs: $ty) {
+                *self = $trait::$method(*self, rhs);
+            }
+        }
+
+        impl $assign_trait<&$ty> for $ty {
+            #[track_caller]
+            fn $assign_method(&mut self, rhs: &$ty) {
+                *self = $trait::$method(*self, *rhs);
+            }
+        }
+    };
+}
+
+binop_impls!(DoubleInt: Add, add, AddAssign, add_assign => checked_add, "attempt to add with overflow");
+binop_impls!(DoubleInt: Sub, sub, SubAssign, sub_assign => checked_sub, "attempt to subtract with overflow");
+binop_impls!(DoubleInt: Mul, mul, MulAssign, mul_assign => checked_mul, "attempt to multiply with overflow");
+binop_impls!(DoubleInt: Div, div, DivAssign, div_assign => checked_div, "attempt to divide by zero");
+binop_impls!(DoubleInt: Rem, rem, RemAssign, rem_assign => checked_rem, "attempt to calculate the remainder with a divisor of zero");
+
+binop_impls!(DoubleUint: Add, add, AddAssign, add_assign => checked_add, "attempt to add with overflow");
+binop_impls!(DoubleUint: Sub, sub, SubAssign, sub_assign => checked_sub, "attempt to subtract with overflow");
+binop_impls!(DoubleUint: Mul, mul, MulAssign, mul_assign => checked_mul, "attempt to multiply with overflow");
+binop_impls!(DoubleUint: Div, div, DivAssign, div_assign => checked_div, "attempt to divide by zero");
+binop_impls!(DoubleUint: Rem, rem, RemAssign, rem_assign => checked_rem, "attempt to calculate the remainder with a divisor of zero");
+
+macro_rules! primitive_binop_impls {
+    ($this:ident: $trait:ident, $method:ident, $assign_trait:ident, $assign_method:ident; $($ty:ty),+) => {$(
+        impl $trait<$ty> for $this {
+            type Output = $this;
+
+            #[track_caller]
+            fn $method(self, rhs: $ty) -> Self::Output {
+                $trait::$method(self, $this::from(rhs))
+            }
+        }
+
+        impl $trait<$this> for $ty {
+            type Output = $this;
+
+            #[track_caller]
+            fn $method(self, rhs: $this) -> Self::Output {
+                $trait::$method($this::from(self), rhs)
+            }
+        }
+
+        impl $assign_trait<$ty> for $this {
+            #[track_caller]
+            fn $assign_method(&mut self, rhs: $ty) {
+                *self = $trait::$method(*self, $this::from(rhs));
+            }
+        }
+    )+};
+}
+
+primitive_binop_impls!(DoubleInt: Add, add, AddAssign, add_assign; u8, u16, u32, i8, i16, i32);
+primitive_binop_impls!(DoubleInt: Sub, sub, SubAssign, sub_assign; u8, u16, u32, i8, i16, i32);
+primitive_binop_impls!(DoubleInt: Mul, mul, MulAssign, mul_assign; u8, u16, u32, i8, i16, i32);
+primitive_binop_impls!(DoubleInt: Div, div, DivAssign, div_assign; u8, u16, u32, i8, i16, i32);
+primitive_binop_impls!(DoubleInt: Rem, rem, RemAssign, rem_assign; u8, u16, u32, i8, i16, i32);
+
+primitive_binop_impls!(DoubleUint: Add, add, AddAssign, add_assign; u8, u16, u32);
+primitive_binop_impls!(DoubleUint: Sub, sub, SubAssign, sub_assign; u8, u16, u32);
+primitive_binop_impls!(DoubleUint: Mul, mul, MulAssign, mul_assign; u8, u16, u32);
+primitive_binop_impls!(DoubleUint: Div, div, DivAssign, div_assign; u8, u16, u32);
+primitive_binop_impls!(DoubleUint: Rem, rem, RemAssign, rem_assign; u8, u16, u32);
+
+impl Neg for DoubleInt {
+    type Output = DoubleInt;
+
+    fn neg(self) -> Self::Output {
+        // negation can never leave the double-int bounds
+        DoubleInt(-self.0)
+    }
+}
+
+impl Neg for &DoubleInt {
+    type Output = DoubleInt;
+
+    fn neg(self) -> Self::Output {
+        -*self
+    }
+}
@@ -0,0 +1,245 @@
+use core::str::FromStr;
+
+use crate::{
+    BoundedDoubleInt, DoubleInt, DoubleIntError, DoubleUint, I64AsString, I64OrString, SingleInt,
+};
+
+impl DoubleInt {
+    /// Parses a double-int from a string in the given base.
+    ///
+    /// The string may begin with a `+` or `-` sign followed by one or more digits. Digits above
+    /// 9 are accepted in either case, as with [`i64::from_str_radix()`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `radix` is not in the range 2..=36.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use double_int::{DoubleInt, DoubleIntError};
+    /// assert_eq!(DoubleInt::from_str_radix("-ff", 16).unwrap(), -255);
+    /// assert_eq!(DoubleInt::from_str_radix("1fffffffffffff", 16).unwrap(), DoubleInt::MAX);
+    ///
+    /// assert_eq!(
+    ///     DoubleInt::from_str_radix("20000000000000", 16).unwrap_err(),
+    ///     DoubleIntError::TooLarge(9_007_199_254_740_992),
+    /// );
+    /// assert_eq!(
+    ///     DoubleInt::from_str_radix("12", 2).unwrap_err(),
+    ///     DoubleIntError::InvalidDigit,
+    /// );
+    /// ```
+    pub fn from_str_radix(src: &str, radix: u32) -> Result<Self, DoubleIntError> {
+        match parse_radix(src, radix)? {
+            (true, magnitude) => DoubleInt::from_i128(negate(magnitude)),
+            (false, magnitude) => DoubleInt::from_u128(magnitude),
+        }
+    }
+}
+
+impl FromStr for DoubleInt {
+    type Err = DoubleIntError;
+
+    /// Parses a base 10 double-int from a string.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use double_int::{DoubleInt, DoubleIntError};
+    /// assert_eq!("42".parse::<DoubleInt>().unwrap(), 42);
+    /// assert_eq!("-9007199254740991".parse::<DoubleInt>().unwrap(), DoubleInt::MIN);
+    ///
+    /// assert_eq!(
+    ///     "-9007199254740992".parse::<DoubleInt>().unwrap_err(),
+    ///     DoubleIntError::TooSmall(-9_007_199_254_740_992),
+    /// );
+    /// assert_eq!("".parse::<DoubleInt>().unwrap_err(), DoubleIntError::Empty);
+    /// assert_eq!("4.2".parse::<DoubleInt>().unwrap_err(), DoubleIntError::InvalidDigit);
+    /// ```
+    fn from_str(src: &str) -> Result<Self, Self::Err> {
+        DoubleInt::from_str_radix(src, 10)
+    }
+}
+
+impl DoubleUint {
+    /// Parses a double-uint from a string in the given base.
+    ///
+    /// The string may begin with a `+` sign followed by one or more digits. A `-` sign is only
+    /// accepted if the value is zero. Digits above 9 are accepted in either case, as with
+    /// [`u64::from_str_radix()`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `radix` is not in the range 2..=36.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use double_int::{DoubleUint, DoubleIntError};
+    /// assert_eq!(DoubleUint::from_str_radix("ff", 16).unwrap(), 255_u64);
+    /// assert_eq!(DoubleUint::from_str_radix("1fffffffffffff", 16).unwrap(), DoubleUint::MAX);
+    ///
+    /// assert_eq!(
+    ///     DoubleUint::from_str_radix("-ff", 16).unwrap_err(),
+    ///     DoubleIntError::Negative(-255),
+    /// );
+    /// ```
+    pub fn from_str_radix(src: &str, radix: u32) -> Result<Self, DoubleIntError> {
+        match parse_radix(src, radix)? {
+            (true, magnitude) => DoubleUint::from_i128(negate(magnitude)),
+            (false, magnitude) => DoubleUint::from_u128(magnitude),
+        }
+    }
+}
+
+impl FromStr for DoubleUint {
+    type Err = DoubleIntError;
+
+    /// Parses a base 10 double-uint from a string.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use double_int::{DoubleUint, DoubleIntError};
+    /// assert_eq!("42".parse::<DoubleUint>().unwrap(), 42_u64);
+    ///
+    /// assert_eq!(
+    ///     "9007199254740992".parse::<DoubleUint>().unwrap_err(),
+    ///     DoubleIntError::TooLarge(9_007_199_254_740_992),
+    /// );
+    /// assert_eq!("-1".parse::<DoubleUint>().unwrap_err(), DoubleIntError::Negative(-1));
+    /// ```
+    fn from_str(src: &str) -> Result<Self, Self::Err> {
+        DoubleUint::from_str_radix(src, 10)
+    }
+}
+
+impl<const MIN: i64, const MAX: i64> FromStr for BoundedDoubleInt<MIN, MAX> {
+    type Err = DoubleIntError;
+
+    /// Parses a base 10 bounded double-int from a string.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use double_int::{BoundedDoubleInt, DoubleIntError};
+    /// assert_eq!("42".parse::<BoundedDoubleInt<1, 500>>().unwrap(), 42);
+    ///
+    /// assert_eq!(
+    ///     "501".parse::<BoundedDoubleInt<1, 500>>().unwrap_err(),
+    ///     DoubleIntError::OutOfRange { value: 501, min: 1, max: 500 },
+    /// );
+    /// ```
+    fn from_str(src: &str) -> Result<Self, Self::Err> {
+        parse_i128(src).and_then(BoundedDoubleInt::from_i128)
+    }
+}
+
+impl FromStr for SingleInt {
+    type Err = DoubleIntError;
+
+    /// Parses a base 10 single-int from a string.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use double_int::{DoubleIntError, SingleInt};
+    /// assert_eq!("-16777215".parse::<SingleInt>().unwrap(), SingleInt::MIN);
+    ///
+    /// assert_eq!(
+    ///     "16777216".parse::<SingleInt>().unwrap_err(),
+    ///     DoubleIntError::OutOfRange { value: 16_777_216, min: -16_777_215, max: 16_777_215 },
+    /// );
+    /// ```
+    fn from_str(src: &str) -> Result<Self, Self::Err> {
+        parse_i128(src).and_then(SingleInt::from_i128)
+    }
+}
+
+impl FromStr for I64AsString {
+    type Err = DoubleIntError;
+
+    /// Parses a base 10 64-bit integer from a string.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use double_int::{DoubleIntError, I64AsString};
+    /// assert_eq!("-9223372036854775808".parse::<I64AsString>().unwrap(), i64::MIN);
+    ///
+    /// assert_eq!(
+    ///     "9223372036854775808".parse::<I64AsString>().unwrap_err(),
+    ///     DoubleIntError::OutOfRange {
+    ///         value: 9_223_372_036_854_775_808,
+    ///         min: i64::MIN,
+    ///         max: i64::MAX,
+    ///     },
+    /// );
+    /// ```
+    fn from_str(src: &str) -> Result<Self, Self::Err> {
+        parse_i128(src).and_then(I64AsString::from_i128)
+    }
+}
+
+impl FromStr for I64OrString {
+    type Err = DoubleIntError;
+
+    /// Parses a base 10 64-bit integer from a string.
+    fn from_str(src: &str) -> Result<Self, Self::Err> {
+        parse_i128(src).and_then(I64OrString::from_i128)
+    }
+}
+
+/// Parses an optionally signed integer into its sign (`true` if negative) and magnitude.
+///
+/// Magnitudes that do not fit into a `u128` are saturated so that out-of-bounds errors can still
+/// report the parsed value.
+fn parse_radix(src: &str, radix: u32) -> Result<(bool, u128), DoubleIntError> {
+    assert!(
+        (2..=36).contains(&radix),
+        "from_str_radix: radix must lie in the range `[2, 36]` - found {}",
+        radix,
+    );
+
+    let (negative, digits) = match src.as_bytes() {
+        [b'-', digits @ ..] => (true, digits),
+        [b'+', digits @ ..] => (false, digits),
+        digits => (false, digits),
+    };
+
+    if digits.is_empty() {
+        return Err(DoubleIntError::Empty);
+    }
+
+    let mut magnitude = 0_u128;
+
+    for &digit in digits {
+        let digit = (digit as char)
+            .to_digit(radix)
+            .ok_or(DoubleIntError::InvalidDigit)?;
+
+        magnitude = magnitude
+            .saturating_mul(radix as u128)
+            .saturating_add(digit as u128);
+    }
+
+    Ok((negative, magnitude))
+}
+
+/// Parses an optionally signed base 10 integer, saturating at the bounds of `i128`.
+pub(crate) fn parse_i128(src: &str) -> Result<i128, DoubleIntError> {
+    match parse_radix(src, 10)? {
+        (true, magnitude) => Ok(negate(magnitude)),
+        (false, magnitude) => Ok(i128::try_from(magnitude).unwrap_or(i128::MAX)),
+    }
+}
+
+/// Negates a parsed magnitude, saturating at `i128::MIN`.
+fn negate(magnitude: u128) -> i128 {
+    if magnitude > i128::MAX as u128 {
+        i128::MIN
+    } else {
+        -(magnitude as i128)
+    }
+}
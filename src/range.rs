@@ -0,0 +1,147 @@
+use core::iter::FusedIterator;
+
+use crate::DoubleInt;
+
+impl DoubleInt {
+    /// Returns an iterator over the double-ints in `start..end`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use double_int::DoubleInt;
+    /// let pages = DoubleInt::range(DoubleInt::from(1), DoubleInt::from(4));
+    /// assert!(pages.eq([1, 2, 3]));
+    ///
+    /// let cursors = DoubleInt::range(DoubleInt::from(0), DoubleInt::from(100)).step_by(25);
+    /// assert!(cursors.eq([0, 25, 50, 75]));
+    ///
+    /// // ranges can be reversed on all targets but reversing a stepped range requires
+    /// // `ExactSizeIterator`, which is only implemented on 64-bit targets
+    /// let pages = DoubleInt::range(DoubleInt::from(1), DoubleInt::from(4));
+    /// assert!(pages.rev().eq([3, 2, 1]));
+    /// ```
+    pub const fn range(start: DoubleInt, end: DoubleInt) -> DoubleIntRange {
+        DoubleIntRange {
+            start: start.0,
+            end: end.0,
+        }
+    }
+
+    /// Returns an iterator over the double-ints in `start..=end`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use double_int::DoubleInt;
+    /// let last = DoubleInt::range_inclusive(DoubleInt::MAX - 1, DoubleInt::MAX);
+    /// assert_eq!(last.size_hint(), (2, Some(2)));
+    /// assert_eq!(last.last().unwrap(), DoubleInt::MAX);
+    /// ```
+    pub const fn range_inclusive(start: DoubleInt, end: DoubleInt) -> DoubleIntRange {
+        // end is at most 2^53 - 1 so cannot overflow
+        DoubleIntRange {
+            start: start.0,
+            end: end.0 + 1,
+        }
+    }
+}
+
+/// Iterator over a range of double-ints.
+///
+/// Created by [`DoubleInt::range()`] and [`DoubleInt::range_inclusive()`]. Never yields values
+/// outside the double-int bounds. Skipping with [`Iterator::nth()`], and so [`Iterator::step_by()`],
+/// runs in constant time.
+///
+/// [`ExactSizeIterator`] is only implemented on 64-bit targets since the length of a double-int
+/// range can exceed `usize::MAX` elsewhere.
+///
+/// # Examples
+///
+/// ```
+/// # use double_int::DoubleInt;
+/// let mut ids = DoubleInt::range_inclusive(DoubleInt::MAX - 9, DoubleInt::MAX);
+/// assert_eq!(ids.size_hint(), (10, Some(10)));
+///
+/// assert_eq!(ids.nth(4).unwrap(), DoubleInt::MAX - 5);
+/// assert_eq!(ids.next_back().unwrap(), DoubleInt::MAX);
+/// assert!(ids.nth(4).is_none());
+/// assert!(ids.next().is_none());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct DoubleIntRange {
+    start: i64,
+    end: i64,
+}
+
+impl DoubleIntRange {
+    /// Returns the number of remaining values.
+    const fn remaining(&self) -> u64 {
+        if self.start < self.end {
+            // both bounds are within 2^53 of zero so cannot overflow
+            (self.end - self.start) as u64
+        } else {
+            0
+        }
+    }
+}
+
+impl Iterator for DoubleIntRange {
+    type Item = DoubleInt;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.start < self.end {
+            let val = DoubleInt(self.start);
+            self.start += 1;
+            Some(val)
+        } else {
+            None
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match usize::try_from(self.remaining()) {
+            Ok(len) => (len, Some(len)),
+            Err(_) => (usize::MAX, None),
+        }
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        if (n as u64) < self.remaining() {
+            self.start += n as i64;
+            self.next()
+        } else {
+            self.start = self.end;
+            None
+        }
+    }
+
+    fn last(mut self) -> Option<Self::Item> {
+        self.next_back()
+    }
+}
+
+impl DoubleEndedIterator for DoubleIntRange {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.start < self.end {
+            self.end -= 1;
+            Some(DoubleInt(self.end))
+        } else {
+            None
+        }
+    }
+
+    fn nth_back(&mut self, n: usize) -> Option<Self::Item> {
+        if (n as u64) < self.remaining() {
+            self.end -= n as i64;
+            self.next_back()
+        } else {
+            self.end = self.start;
+            None
+        }
+    }
+}
+
+#[cfg(target_pointer_width = "64")]
+impl ExactSizeIterator for DoubleIntRange {}
+
+impl FusedIterator for DoubleIntRange {}
@@ -0,0 +1,114 @@
+use crate::{DoubleInt, DoubleIntError};
+
+/// Strategy used to turn a float with a fractional part into a double-int.
+///
+/// See [`DoubleInt::from_f64_rounded()`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RoundingMode {
+    /// Rounds towards zero.
+    Truncate,
+
+    /// Rounds towards negative infinity.
+    Floor,
+
+    /// Rounds towards positive infinity.
+    Ceil,
+
+    /// Rounds to the nearest integer, with halfway cases rounded away from zero.
+    HalfAwayFromZero,
+
+    /// Rounds to the nearest integer, with halfway cases rounded to the nearest even integer.
+    HalfEven,
+}
+
+impl RoundingMode {
+    /// Rounds `val`, which must be finite and within the double-int bounds.
+    fn round(self, val: f64) -> i64 {
+        // truncation is exact for all floats within the double-int bounds, and so is the
+        // subtraction since both operands are within one of each other
+        let trunc = val as i64;
+        let frac = val - trunc as f64;
+
+        match self {
+            RoundingMode::Truncate => trunc,
+
+            RoundingMode::Floor if frac < 0.0 => trunc - 1,
+            RoundingMode::Floor => trunc,
+
+            RoundingMode::Ceil if frac > 0.0 => trunc + 1,
+            RoundingMode::Ceil => trunc,
+
+            RoundingMode::HalfAwayFromZero if frac >= 0.5 => trunc + 1,
+            RoundingMode::HalfAwayFromZero if frac <= -0.5 => trunc - 1,
+            RoundingMode::HalfAwayFromZero => trunc,
+
+            RoundingMode::HalfEven if frac > 0.5 || (frac == 0.5 && trunc % 2 != 0) => trunc + 1,
+            RoundingMode::HalfEven if frac < -0.5 || (frac == -0.5 && trunc % 2 != 0) => trunc - 1,
+            RoundingMode::HalfEven => trunc,
+        }
+    }
+}
+
+impl DoubleInt {
+    /// Converts a float into a double-int, rounding any fractional part using `mode`.
+    ///
+    /// Returns an error if `val` is NaN, infinite, or outside the double-int bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use double_int::{DoubleInt, DoubleIntError, RoundingMode};
+    /// assert_eq!(DoubleInt::from_f64_rounded(2.5, RoundingMode::Truncate).unwrap(), 2);
+    /// assert_eq!(DoubleInt::from_f64_rounded(-2.5, RoundingMode::Floor).unwrap(), -3);
+    /// assert_eq!(DoubleInt::from_f64_rounded(2.1, RoundingMode::Ceil).unwrap(), 3);
+    /// assert_eq!(DoubleInt::from_f64_rounded(-2.5, RoundingMode::HalfAwayFromZero).unwrap(), -3);
+    /// assert_eq!(DoubleInt::from_f64_rounded(2.5, RoundingMode::HalfEven).unwrap(), 2);
+    /// assert_eq!(DoubleInt::from_f64_rounded(3.5, RoundingMode::HalfEven).unwrap(), 4);
+    ///
+    /// assert!(matches!(
+    ///     DoubleInt::from_f64_rounded(f64::NAN, RoundingMode::Truncate),
+    ///     Err(DoubleIntError::NotFinite(_)),
+    /// ));
+    /// assert!(matches!(
+    ///     DoubleInt::from_f64_rounded(1e20, RoundingMode::Truncate),
+    ///     Err(DoubleIntError::TooLarge(_)),
+    /// ));
+    /// ```
+    pub fn from_f64_rounded(val: f64, mode: RoundingMode) -> Result<Self, DoubleIntError> {
+        if !val.is_finite() || val > DoubleInt::MAX.0 as f64 || val < DoubleInt::MIN.0 as f64 {
+            // floats outside the double-int bounds never have a fractional part
+            return DoubleInt::from_f64(val);
+        }
+
+        Ok(DoubleInt(mode.round(val)))
+    }
+
+    /// Converts a float into a double-int, rounding any fractional part using `mode` and
+    /// saturating at the double-int bounds.
+    ///
+    /// NaN is converted to zero, matching the behavior of `as` casts.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use double_int::{DoubleInt, RoundingMode};
+    /// assert_eq!(DoubleInt::from_f64_saturating(2.5, RoundingMode::HalfEven), 2);
+    /// assert_eq!(DoubleInt::from_f64_saturating(1e20, RoundingMode::Floor), 9_007_199_254_740_991_i64);
+    /// assert_eq!(
+    ///     DoubleInt::from_f64_saturating(f64::NEG_INFINITY, RoundingMode::Floor),
+    ///     -9_007_199_254_740_991_i64,
+    /// );
+    /// assert_eq!(DoubleInt::from_f64_saturating(f64::NAN, RoundingMode::Ceil), 0);
+    /// ```
+    pub fn from_f64_saturating(val: f64, mode: RoundingMode) -> Self {
+        if val.is_nan() {
+            DoubleInt(0)
+        } else if val > DoubleInt::MAX.0 as f64 {
+            DoubleInt::MAX
+        } else if val < DoubleInt::MIN.0 as f64 {
+            DoubleInt::MIN
+        } else {
+            DoubleInt(mode.round(val))
+        }
+    }
+}
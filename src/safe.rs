@@ -0,0 +1,171 @@
+use crate::{DoubleInt, DoubleIntError, DoubleUint, RoundingMode};
+
+mod private {
+    pub trait Sealed {}
+}
+
+/// Extension methods for checking whether primitive numbers are double-ints or double-uints.
+///
+/// This mirrors JavaScript's [`Number.isSafeInteger()`][is_safe_integer] and is implemented for
+/// all primitive integer and float types.
+///
+/// # Examples
+///
+/// ```
+/// use double_int::{DoubleInt, DoubleIntError, SafeInteger as _};
+///
+/// assert!(42_u64.is_double_int());
+/// assert!(9_007_199_254_740_991_i64.is_double_int());
+/// assert!(!9_007_199_254_740_992_i64.is_double_int());
+/// assert!(42.0_f64.is_double_int());
+/// assert!(!4.2_f64.is_double_int());
+/// assert!(!f64::NAN.is_double_int());
+///
+/// assert_eq!(42_usize.to_double_int().unwrap(), 42);
+/// assert_eq!(u64::MAX.to_double_int().unwrap_err(), DoubleIntError::TooLarge(u64::MAX as u128));
+///
+/// assert_eq!(u64::MAX.to_double_int_saturating(), DoubleInt::MAX);
+/// assert_eq!(f64::NEG_INFINITY.to_double_int_saturating(), DoubleInt::MIN);
+/// assert_eq!((-4.7_f64).to_double_int_saturating(), -4);
+///
+/// assert!(42_i8.is_double_uint());
+/// assert!(!(-1_i64).is_double_uint());
+/// assert_eq!((-1_i64).to_double_uint().unwrap_err(), DoubleIntError::Negative(-1));
+/// assert_eq!((-4.7_f64).to_double_uint_saturating(), 0_u64);
+/// ```
+///
+/// [is_safe_integer]: https://developer.mozilla.org/en-US/docs/Web/JavaScript/Reference/Global_Objects/Number/isSafeInteger
+pub trait SafeInteger: Copy + private::Sealed {
+    /// Returns true if this value is an integer within the double-int bounds.
+    fn is_double_int(self) -> bool {
+        self.to_double_int().is_ok()
+    }
+
+    /// Converts this value into a double-int, returning an error if it is not an integer within
+    /// the double-int bounds.
+    fn to_double_int(self) -> Result<DoubleInt, DoubleIntError>;
+
+    /// Converts this value into a double-int, clamping it to the double-int bounds.
+    ///
+    /// Floats are truncated towards zero and NaN is converted to zero, matching the behavior of
+    /// `as` casts.
+    fn to_double_int_saturating(self) -> DoubleInt;
+
+    /// Returns true if this value is an integer within the double-uint bounds.
+    fn is_double_uint(self) -> bool {
+        self.to_double_uint().is_ok()
+    }
+
+    /// Converts this value into a double-uint, returning an error if it is not an integer within
+    /// the double-uint bounds.
+    fn to_double_uint(self) -> Result<DoubleUint, DoubleIntError>;
+
+    /// Converts this value into a double-uint, clamping it to the double-uint bounds.
+    ///
+    /// Floats are truncated towards zero and NaN is converted to zero, matching the behavior of
+    /// `as` casts.
+    fn to_double_uint_saturating(self) -> DoubleUint;
+}
+
+macro_rules! infallible_safe_integer_impl {
+    ($ty:ty) => {
+        impl private::Sealed for $ty {}
+
+        impl SafeInteger for $ty {
+            fn is_double_int(self) -> bool {
+                true
+            }
+
+            fn to_double_int(self) -> Result<DoubleInt, DoubleIntError> {
+                Ok(DoubleInt::from(self))
+            }
+
+            fn to_double_int_saturating(self) -> DoubleInt {
+                DoubleInt::from(self)
+            }
+
+            fn to_double_uint(self) -> Result<DoubleUint, DoubleIntError> {
+                DoubleUint::from_i128(self as i128)
+            }
+
+            fn to_double_uint_saturating(self) -> DoubleUint {
+                // values fit within the double-int bounds so can only be out of bounds if negative
+                self.to_double_uint().unwrap_or(DoubleUint::MIN)
+            }
+        }
+    };
+}
+
+infallible_safe_integer_impl!(u8);
+infallible_safe_integer_impl!(u16);
+infallible_safe_integer_impl!(u32);
+infallible_safe_integer_impl!(i8);
+infallible_safe_integer_impl!(i16);
+infallible_safe_integer_impl!(i32);
+
+macro_rules! fallible_safe_integer_impl {
+    ($ty:ty) => {
+        impl private::Sealed for $ty {}
+
+        impl SafeInteger for $ty {
+            fn to_double_int(self) -> Result<DoubleInt, DoubleIntError> {
+                DoubleInt::try_from(self)
+            }
+
+            fn to_double_int_saturating(self) -> DoubleInt {
+                match DoubleInt::try_from(self) {
+                    Ok(val) => val,
+                    Err(DoubleIntError::TooSmall(_)) => DoubleInt::MIN,
+                    Err(_) => DoubleInt::MAX,
+                }
+            }
+
+            fn to_double_uint(self) -> Result<DoubleUint, DoubleIntError> {
+                DoubleUint::try_from(self)
+            }
+
+            fn to_double_uint_saturating(self) -> DoubleUint {
+                match DoubleUint::try_from(self) {
+                    Ok(val) => val,
+                    Err(DoubleIntError::Negative(_)) => DoubleUint::MIN,
+                    Err(_) => DoubleUint::MAX,
+                }
+            }
+        }
+    };
+}
+
+fallible_safe_integer_impl!(u64);
+fallible_safe_integer_impl!(u128);
+fallible_safe_integer_impl!(usize);
+fallible_safe_integer_impl!(i64);
+fallible_safe_integer_impl!(i128);
+fallible_safe_integer_impl!(isize);
+
+macro_rules! float_safe_integer_impl {
+    ($ty:ty) => {
+        impl private::Sealed for $ty {}
+
+        impl SafeInteger for $ty {
+            fn to_double_int(self) -> Result<DoubleInt, DoubleIntError> {
+                DoubleInt::try_from(self)
+            }
+
+            fn to_double_int_saturating(self) -> DoubleInt {
+                DoubleInt::from_f64_saturating(f64::from(self), RoundingMode::Truncate)
+            }
+
+            fn to_double_uint(self) -> Result<DoubleUint, DoubleIntError> {
+                DoubleUint::try_from(self)
+            }
+
+            fn to_double_uint_saturating(self) -> DoubleUint {
+                // negative values saturate to zero
+                DoubleUint::try_from(self.to_double_int_saturating()).unwrap_or(DoubleUint::MIN)
+            }
+        }
+    };
+}
+
+float_safe_integer_impl!(f32);
+float_safe_integer_impl!(f64);
@@ -0,0 +1,155 @@
+use core::fmt;
+
+use serde::de::{self, DeserializeSeed, Deserializer};
+
+use crate::{parse::parse_i128, DoubleInt, DoubleIntError};
+
+/// Deserializes a double-int that must also lie within runtime bounds.
+///
+/// Useful when limits come from configuration rather than code; see [`BoundedDoubleInt`] for
+/// bounds known at compile time. Values outside `min..=max` produce
+/// [`DoubleIntError::OutOfRange`] errors mentioning the configured limits.
+///
+/// The fields are public for convenience but nothing prevents `min > max`, in which case every
+/// value is rejected. Use [`DoubleIntSeed::new()`] to check the limits up front.
+///
+/// # Examples
+///
+/// ```
+/// # use double_int::{DoubleInt, DoubleIntSeed};
+/// use serde::de::DeserializeSeed as _;
+///
+/// let quota = DoubleIntSeed {
+///     min: DoubleInt::from(0),
+///     max: DoubleInt::from(1000),
+/// };
+///
+/// let mut de = serde_json::Deserializer::from_str("42");
+/// assert_eq!(quota.deserialize(&mut de).unwrap(), 42);
+///
+/// let mut de = serde_json::Deserializer::from_str("1001");
+/// assert_eq!(
+///     quota.deserialize(&mut de).unwrap_err().to_string(),
+///     "value 1001 is outside the range 0..=1000 at line 1 column 4",
+/// );
+///
+/// let mut de = serde_json::Deserializer::from_str("9007199254740992");
+/// assert_eq!(
+///     quota.deserialize(&mut de).unwrap_err().to_string(),
+///     "value 9007199254740992 is outside the range 0..=1000 at line 1 column 16",
+/// );
+/// ```
+///
+/// [`BoundedDoubleInt`]: crate::BoundedDoubleInt
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DoubleIntSeed {
+    /// The smallest accepted value.
+    pub min: DoubleInt,
+
+    /// The largest accepted value.
+    pub max: DoubleInt,
+}
+
+impl DoubleIntSeed {
+    /// Constructs a new seed, returning `None` if `min > max`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use double_int::{DoubleInt, DoubleIntSeed};
+    /// assert!(DoubleIntSeed::new(DoubleInt::from(1), DoubleInt::from(100)).is_some());
+    /// assert!(DoubleIntSeed::new(DoubleInt::from(100), DoubleInt::from(1)).is_none());
+    /// ```
+    pub const fn new(min: DoubleInt, max: DoubleInt) -> Option<Self> {
+        if min.0 <= max.0 {
+            Some(DoubleIntSeed { min, max })
+        } else {
+            None
+        }
+    }
+
+    /// Checks that `val` lies within `min..=max`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use double_int::{DoubleInt, DoubleIntError, DoubleIntSeed};
+    /// let seed = DoubleIntSeed {
+    ///     min: DoubleInt::from(1),
+    ///     max: DoubleInt::from(100),
+    /// };
+    ///
+    /// assert_eq!(seed.check(42).unwrap(), 42);
+    /// assert_eq!(
+    ///     seed.check(0).unwrap_err(),
+    ///     DoubleIntError::OutOfRange { value: 0, min: 1, max: 100 },
+    /// );
+    /// ```
+    pub fn check(self, val: impl Into<i128>) -> Result<DoubleInt, DoubleIntError> {
+        let val = val.into();
+
+        if val < self.min.0 as i128 || val > self.max.0 as i128 {
+            Err(DoubleIntError::OutOfRange {
+                value: val,
+                min: self.min.0,
+                max: self.max.0,
+            })
+        } else {
+            Ok(DoubleInt(val as i64))
+        }
+    }
+}
+
+impl Default for DoubleIntSeed {
+    /// Returns a seed accepting the full double-int range.
+    fn default() -> Self {
+        DoubleIntSeed {
+            min: DoubleInt::MIN,
+            max: DoubleInt::MAX,
+        }
+    }
+}
+
+impl<'de> DeserializeSeed<'de> for DoubleIntSeed {
+    type Value = DoubleInt;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        deserializer.deserialize_i64(DoubleIntSeedVisitor(self))
+    }
+}
+
+/// Visitor that accepts integers and numeric strings within the bounds of a [`DoubleIntSeed`].
+struct DoubleIntSeedVisitor(DoubleIntSeed);
+
+impl<'de> de::Visitor<'de> for DoubleIntSeedVisitor {
+    type Value = DoubleInt;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "an integer in the range {}..={}", self.0.min, self.0.max)
+    }
+
+    fn visit_i64<E: de::Error>(self, val: i64) -> Result<Self::Value, E> {
+        self.0.check(val).map_err(E::custom)
+    }
+
+    fn visit_i128<E: de::Error>(self, val: i128) -> Result<Self::Value, E> {
+        self.0.check(val).map_err(E::custom)
+    }
+
+    fn visit_u64<E: de::Error>(self, val: u64) -> Result<Self::Value, E> {
+        self.0.check(val).map_err(E::custom)
+    }
+
+    fn visit_u128<E: de::Error>(self, val: u128) -> Result<Self::Value, E> {
+        // values outside i128 are saturated, as with other out-of-range errors
+        self.0
+            .check(i128::try_from(val).unwrap_or(i128::MAX))
+            .map_err(E::custom)
+    }
+
+    fn visit_str<E: de::Error>(self, val: &str) -> Result<Self::Value, E> {
+        parse_i128(val)
+            .and_then(|val| self.0.check(val))
+            .map_err(E::custom)
+    }
+}
@@ -0,0 +1,214 @@
+use core::borrow::Borrow;
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::{conv::SingleIntVisitor, DoubleInt, DoubleIntError};
+
+/// Integer that can be stored in an IEEE 754 single-precision number without loss of precision.
+///
+/// Represents values in the range -(2^24) + 1..=(2^24) - 1 and is the `f32` counterpart of
+/// [`DoubleInt`]. Useful for validating integers destined for single-precision storage such as
+/// GPU buffers or compact telemetry formats.
+///
+/// Values outside the single-int bounds produce [`DoubleIntError::OutOfRange`] errors.
+///
+/// # Examples
+///
+/// ```
+/// # use double_int::{DoubleInt, SingleInt};
+/// assert_eq!(serde_json::from_str::<SingleInt>("42").unwrap(), 42);
+///
+/// serde_json::from_str::<SingleInt>("4.2").unwrap_err();
+/// assert_eq!(
+///     serde_json::from_str::<SingleInt>("16777216").unwrap_err().to_string(),
+///     "value 16777216 is outside the range -16777215..=16777215 at line 1 column 8",
+/// );
+///
+/// let count = SingleInt::from(255_u8);
+/// assert_eq!(f32::from(count), 255.0);
+/// assert_eq!(DoubleInt::from(count), 255);
+/// assert_eq!(format!("{:#x}", count), "0xff");
+/// ```
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SingleInt(pub(crate) i32);
+
+impl SingleInt {
+    /// The smallest value that can be represented by this type, -(2^24) + 1.
+    pub const MIN: SingleInt = SingleInt(-(2_i32.pow(24)) + 1);
+
+    /// The largest value that can be represented by this type, (2^24) - 1.
+    pub const MAX: SingleInt = SingleInt(2_i32.pow(24) - 1);
+
+    /// Constructs a new single-int, returning `None` if `val` is outside the single-int bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use double_int::SingleInt;
+    /// const COUNT: Option<SingleInt> = SingleInt::new(42);
+    /// assert_eq!(COUNT.unwrap(), 42);
+    ///
+    /// assert!(SingleInt::new(i32::MAX).is_none());
+    /// ```
+    pub const fn new(val: i32) -> Option<Self> {
+        match SingleInt::try_new(val) {
+            Ok(val) => Some(val),
+            Err(_) => None,
+        }
+    }
+
+    /// Constructs a new single-int, returning an error if `val` is outside the single-int bounds.
+    pub const fn try_new(val: i32) -> Result<Self, DoubleIntError> {
+        SingleInt::from_i128(val as i128)
+    }
+
+    /// Constructs a new single-int without checking that `val` is within the single-int bounds.
+    ///
+    /// # Safety
+    ///
+    /// `val` must be within the range [`SingleInt::MIN`]..=[`SingleInt::MAX`].
+    pub const unsafe fn new_unchecked(val: i32) -> Self {
+        SingleInt(val)
+    }
+
+    /// Returns value as a standard type.
+    pub const fn as_i32(self) -> i32 {
+        self.0
+    }
+
+    /// Converts a signed integer, checking that it lies within the single-int bounds.
+    pub(crate) const fn from_i128(val: i128) -> Result<Self, DoubleIntError> {
+        if val < SingleInt::MIN.0 as i128 || val > SingleInt::MAX.0 as i128 {
+            Err(DoubleIntError::OutOfRange {
+                value: val,
+                min: SingleInt::MIN.0 as i64,
+                max: SingleInt::MAX.0 as i64,
+            })
+        } else {
+            Ok(SingleInt(val as i32))
+        }
+    }
+
+    /// Converts an unsigned integer, checking that it lies within the single-int bounds.
+    pub(crate) const fn from_u128(val: u128) -> Result<Self, DoubleIntError> {
+        if val > i128::MAX as u128 {
+            SingleInt::from_i128(i128::MAX)
+        } else {
+            SingleInt::from_i128(val as i128)
+        }
+    }
+
+    /// Converts a float, checking that it is a finite integer within the single-int bounds.
+    fn from_f64(val: f64) -> Result<Self, DoubleIntError> {
+        // floats beyond the single-int bounds can still have a fractional part so check that first
+        match DoubleInt::from_f64(val) {
+            Ok(val) => SingleInt::from_i128(val.0 as i128),
+            // float to int casts saturate
+            Err(DoubleIntError::TooLarge(_) | DoubleIntError::TooSmall(_)) => {
+                SingleInt::from_i128(val as i128)
+            }
+            Err(err) => Err(err),
+        }
+    }
+}
+
+impl TryFrom<DoubleInt> for SingleInt {
+    type Error = DoubleIntError;
+
+    fn try_from(val: DoubleInt) -> Result<Self, Self::Error> {
+        SingleInt::from_i128(val.0 as i128)
+    }
+}
+
+impl TryFrom<f64> for SingleInt {
+    type Error = DoubleIntError;
+
+    /// Converts a float into a single-int, failing if the conversion would lose precision.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use double_int::{DoubleIntError, SingleInt};
+    /// assert_eq!(SingleInt::try_from(42.0_f64).unwrap(), 42);
+    ///
+    /// assert!(matches!(SingleInt::try_from(4.2_f64), Err(DoubleIntError::NotIntegral(_))));
+    /// assert!(matches!(SingleInt::try_from(f64::NAN), Err(DoubleIntError::NotFinite(_))));
+    /// assert!(matches!(SingleInt::try_from(16777216.0_f64), Err(DoubleIntError::OutOfRange { .. })));
+    /// assert!(matches!(SingleInt::try_from(16777215.5_f64), Err(DoubleIntError::NotIntegral(_))));
+    /// assert!(matches!(SingleInt::try_from(-16777215.5_f64), Err(DoubleIntError::NotIntegral(_))));
+    /// ```
+    fn try_from(val: f64) -> Result<Self, Self::Error> {
+        SingleInt::from_f64(val)
+    }
+}
+
+impl TryFrom<f32> for SingleInt {
+    type Error = DoubleIntError;
+
+    /// Converts a float into a single-int, failing if the conversion would lose precision.
+    fn try_from(val: f32) -> Result<Self, Self::Error> {
+        SingleInt::from_f64(f64::from(val))
+    }
+}
+
+impl From<SingleInt> for DoubleInt {
+    fn from(val: SingleInt) -> Self {
+        DoubleInt(val.0 as i64)
+    }
+}
+
+impl From<SingleInt> for f32 {
+    fn from(val: SingleInt) -> Self {
+        // all single-ints are exactly representable by f32
+        val.0 as f32
+    }
+}
+
+impl From<SingleInt> for f64 {
+    fn from(val: SingleInt) -> Self {
+        f64::from(val.0)
+    }
+}
+
+impl From<SingleInt> for i32 {
+    fn from(val: SingleInt) -> Self {
+        val.0
+    }
+}
+
+impl From<SingleInt> for i64 {
+    fn from(val: SingleInt) -> Self {
+        i64::from(val.0)
+    }
+}
+
+impl From<SingleInt> for i128 {
+    fn from(val: SingleInt) -> Self {
+        i128::from(val.0)
+    }
+}
+
+impl Borrow<i32> for SingleInt {
+    /// Borrows the inner value, allowing collections keyed by `SingleInt` to be queried using
+    /// `i32`s.
+    fn borrow(&self) -> &i32 {
+        &self.0
+    }
+}
+
+impl<'de> Deserialize<'de> for SingleInt {
+    /// Deserializes a single-int from an integer.
+    ///
+    /// Numeric strings are also accepted whenever a format provides a string in place of an
+    /// integer, such as for map keys. As with [`DoubleInt`], whether string values are accepted
+    /// depends on the format.
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer.deserialize_i32(SingleIntVisitor)
+    }
+}
+
+impl Serialize for SingleInt {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_i32(self.0)
+    }
+}
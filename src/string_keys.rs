@@ -0,0 +1,119 @@
+//! (De)serialization of maps keyed by [`DoubleInt`]s, [`DoubleUint`]s or [`SingleInt`]s that always
+//! encodes keys as strings.
+//!
+//! Formats such as JSON quote integer keys automatically. However, some string-keyed formats (like
+//! TOML) refuse to serialize integer keys at all. This module serializes keys as base 10 strings
+//! and accepts both integer and string keys when deserializing so that maps keyed by double-ints
+//! can be round-tripped through any self-describing format.
+//!
+//! Use with `#[serde(with = "double_int::string_keys")]` on any map type keyed by `DoubleInt`,
+//! `DoubleUint` or `SingleInt`.
+//!
+//! # Examples
+//!
+//! ```
+//! # use std::collections::BTreeMap;
+//! # use double_int::{DoubleInt, DoubleUint};
+//! #[derive(Debug, PartialEq, serde::Serialize, serde::Deserialize)]
+//! struct Inventory {
+//!     #[serde(with = "double_int::string_keys")]
+//!     counts: BTreeMap<DoubleInt, u32>,
+//!
+//!     #[serde(with = "double_int::string_keys")]
+//!     names: BTreeMap<DoubleUint, String>,
+//! }
+//!
+//! let inventory = Inventory {
+//!     counts: BTreeMap::from([(DoubleInt::from(42), 3), (DoubleInt::MIN, 1)]),
+//!     names: BTreeMap::from([(DoubleUint::MAX, "max".to_owned())]),
+//! };
+//!
+//! let toml = toml::to_string(&inventory).unwrap();
+//! assert_eq!(toml::from_str::<Inventory>(&toml).unwrap(), inventory);
+//! ```
+//!
+//! [`DoubleInt`]: crate::DoubleInt
+//! [`DoubleUint`]: crate::DoubleUint
+//! [`SingleInt`]: crate::SingleInt
+
+use core::{fmt, iter, marker::PhantomData};
+
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
+
+use crate::{conv::Integer, number_or_string::NumberOrStringVisitor};
+
+/// Deserializes a map keyed by double-ints, double-uints or single-ints.
+///
+/// Keys may be integers or numeric strings.
+pub fn deserialize<'de, M, K, V, D>(deserializer: D) -> Result<M, D::Error>
+where
+    M: FromIterator<(K, V)>,
+    K: Integer,
+    V: Deserialize<'de>,
+    D: Deserializer<'de>,
+{
+    deserializer.deserialize_map(StringKeyMapVisitor(PhantomData))
+}
+
+/// Serializes a map keyed by double-ints, double-uints or single-ints, encoding keys as strings.
+pub fn serialize<'a, M, K, V, S>(map: &'a M, serializer: S) -> Result<S::Ok, S::Error>
+where
+    &'a M: IntoIterator<Item = (&'a K, &'a V)>,
+    K: Integer + 'a,
+    V: Serialize + 'a,
+    S: Serializer,
+{
+    serializer.collect_map(map.into_iter().map(|(key, val)| (StringKey(*key), val)))
+}
+
+/// Key that serializes as a base 10 string and deserializes from an integer or string.
+struct StringKey<K>(K);
+
+impl<K: Integer> Serialize for StringKey<K> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(&self.0)
+    }
+}
+
+impl<'de, K: Integer> Deserialize<'de> for StringKey<K> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer
+            .deserialize_any(NumberOrStringVisitor(PhantomData))
+            .map(StringKey)
+    }
+}
+
+/// Visitor for a map `M` built from entries `E`.
+struct StringKeyMapVisitor<M, E>(PhantomData<fn() -> (M, E)>);
+
+impl<'de, M, K, V> de::Visitor<'de> for StringKeyMapVisitor<M, (K, V)>
+where
+    M: FromIterator<(K, V)>,
+    K: Integer,
+    V: Deserialize<'de>,
+{
+    type Value = M;
+
+    fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "a map keyed by {}s", K::NAME)
+    }
+
+    fn visit_map<A: de::MapAccess<'de>>(self, mut access: A) -> Result<Self::Value, A::Error> {
+        let mut err = None;
+
+        let map = iter::from_fn(|| match access.next_entry::<StringKey<K>, V>() {
+            Ok(Some((key, val))) => Some((key.0, val)),
+            Ok(None) => None,
+            Err(e) => {
+                err = Some(e);
+                None
+            }
+        })
+        .collect();
+
+        match err {
+            Some(err) => Err(err),
+            None => Ok(map),
+        }
+    }
+}
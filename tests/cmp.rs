@@ -0,0 +1,182 @@
+use core::cmp::Ordering;
+
+use double_int::DoubleInt;
+
+const MAX: i64 = 9_007_199_254_740_991;
+const MIN: i64 = -9_007_199_254_740_991;
+
+/// Asserts that comparing `lhs` and `rhs` in both directions produces `ord`.
+macro_rules! assert_cmp {
+    ($lhs:expr, $rhs:expr, $ord:expr) => {{
+        let (lhs, rhs, ord): (DoubleInt, _, Option<Ordering>) = ($lhs, $rhs, $ord);
+
+        assert_eq!(lhs.partial_cmp(&rhs), ord, "{:?} <=> {:?}", lhs, rhs);
+        assert_eq!(
+            rhs.partial_cmp(&lhs),
+            ord.map(Ordering::reverse),
+            "{:?} <=> {:?}",
+            rhs,
+            lhs,
+        );
+
+        assert_eq!(
+            lhs == rhs,
+            ord == Some(Ordering::Equal),
+            "{:?} == {:?}",
+            lhs,
+            rhs
+        );
+        assert_eq!(
+            rhs == lhs,
+            ord == Some(Ordering::Equal),
+            "{:?} == {:?}",
+            rhs,
+            lhs
+        );
+    }};
+}
+
+macro_rules! small_int_tests {
+    ($($ty:ident),+) => {$(
+        #[test]
+        fn $ty() {
+            for val in [<$ty>::MIN, <$ty>::MIN + 1, 0, 1, <$ty>::MAX - 1, <$ty>::MAX] {
+                assert_cmp!(DoubleInt::from(val), val, Some(Ordering::Equal));
+                assert_cmp!(DoubleInt::MAX, val, Some(Ordering::Greater));
+                assert_cmp!(DoubleInt::MIN, val, Some(Ordering::Less));
+            }
+        }
+    )+};
+}
+
+small_int_tests!(u8, u16, u32, i8, i16, i32);
+
+macro_rules! wide_int_tests {
+    ($($ty:ident),+) => {$(
+        #[test]
+        fn $ty() {
+            let max = <$ty>::try_from(MAX).unwrap();
+
+            assert_cmp!(DoubleInt::MAX, max, Some(Ordering::Equal));
+            assert_cmp!(DoubleInt::MAX, max - 1, Some(Ordering::Greater));
+            assert_cmp!(DoubleInt::MAX, max + 1, Some(Ordering::Less));
+            assert_cmp!(DoubleInt::MAX, <$ty>::MAX, Some(Ordering::Less));
+
+            assert_cmp!(DoubleInt::from(0), <$ty>::MIN + 1, if <$ty>::MIN == 0 {
+                Some(Ordering::Less)
+            } else {
+                Some(Ordering::Greater)
+            });
+            assert_cmp!(DoubleInt::from(0), 0, Some(Ordering::Equal));
+            assert_cmp!(DoubleInt::MIN, 0, Some(Ordering::Less));
+            assert_cmp!(DoubleInt::MIN, <$ty>::MIN, if <$ty>::MIN == 0 {
+                Some(Ordering::Less)
+            } else {
+                Some(Ordering::Greater)
+            });
+        }
+    )+};
+}
+
+wide_int_tests!(u64, u128, usize, i64, i128, isize);
+
+macro_rules! wide_signed_int_tests {
+    ($($name:ident: $ty:ident),+) => {$(
+        #[test]
+        fn $name() {
+            let min = <$ty>::try_from(MIN).unwrap();
+
+            assert_cmp!(DoubleInt::MIN, min, Some(Ordering::Equal));
+            assert_cmp!(DoubleInt::MIN, min - 1, Some(Ordering::Greater));
+            assert_cmp!(DoubleInt::MIN, min + 1, Some(Ordering::Less));
+            assert_cmp!(DoubleInt::MAX, min, Some(Ordering::Greater));
+            assert_cmp!(DoubleInt::from(-1), -1, Some(Ordering::Equal));
+        }
+    )+};
+}
+
+wide_signed_int_tests!(i64_negative: i64, i128_negative: i128, isize_negative: isize);
+
+#[test]
+fn f64() {
+    assert_cmp!(DoubleInt::MAX, MAX as f64, Some(Ordering::Equal));
+    assert_cmp!(DoubleInt::MAX, MAX as f64 - 1.0, Some(Ordering::Greater));
+    assert_cmp!(DoubleInt::MAX, MAX as f64 + 1.0, Some(Ordering::Less));
+    assert_cmp!(DoubleInt::MIN, MIN as f64, Some(Ordering::Equal));
+    assert_cmp!(DoubleInt::MIN, MIN as f64 - 1.0, Some(Ordering::Greater));
+    assert_cmp!(DoubleInt::MIN, MIN as f64 + 1.0, Some(Ordering::Less));
+
+    assert_cmp!(DoubleInt::from(0), 0.0, Some(Ordering::Equal));
+    assert_cmp!(DoubleInt::from(0), -0.0, Some(Ordering::Equal));
+    assert_cmp!(DoubleInt::from(0), f64::MIN_POSITIVE, Some(Ordering::Less));
+    assert_cmp!(DoubleInt::from(4), 4.2, Some(Ordering::Less));
+    assert_cmp!(DoubleInt::from(5), 4.2, Some(Ordering::Greater));
+    assert_cmp!(DoubleInt::from(-4), -4.2, Some(Ordering::Greater));
+
+    assert_cmp!(DoubleInt::MAX, f64::MAX, Some(Ordering::Less));
+    assert_cmp!(DoubleInt::MIN, f64::MIN, Some(Ordering::Greater));
+    assert_cmp!(DoubleInt::MAX, f64::INFINITY, Some(Ordering::Less));
+    assert_cmp!(DoubleInt::MIN, f64::NEG_INFINITY, Some(Ordering::Greater));
+    assert_cmp!(DoubleInt::from(0), f64::NAN, None);
+}
+
+#[test]
+fn f32() {
+    // f32 has 24 bits of precision so the double-int bounds are rounded up to 2^53
+    assert_cmp!(DoubleInt::MAX, MAX as f32, Some(Ordering::Less));
+    assert_cmp!(DoubleInt::MIN, MIN as f32, Some(Ordering::Greater));
+    assert_cmp!(
+        DoubleInt::from(16_777_216),
+        16_777_216.0_f32,
+        Some(Ordering::Equal)
+    );
+    assert_cmp!(
+        DoubleInt::from(16_777_217),
+        16_777_216.0_f32,
+        Some(Ordering::Greater)
+    );
+
+    assert_cmp!(DoubleInt::from(0), 0.0_f32, Some(Ordering::Equal));
+    assert_cmp!(DoubleInt::from(0), -0.0_f32, Some(Ordering::Equal));
+    assert_cmp!(DoubleInt::from(4), 4.2_f32, Some(Ordering::Less));
+    assert_cmp!(DoubleInt::from(-4), -4.2_f32, Some(Ordering::Greater));
+
+    assert_cmp!(DoubleInt::MAX, f32::MAX, Some(Ordering::Less));
+    assert_cmp!(DoubleInt::MIN, f32::MIN, Some(Ordering::Greater));
+    assert_cmp!(DoubleInt::MAX, f32::INFINITY, Some(Ordering::Less));
+    assert_cmp!(DoubleInt::from(0), f32::NAN, None);
+}
+
+#[test]
+fn double_int() {
+    assert_eq!(DoubleInt::MIN.cmp(&DoubleInt::MAX), Ordering::Less);
+    assert_eq!(DoubleInt::MAX.cmp(&DoubleInt::MAX), Ordering::Equal);
+
+    let mut vals = [DoubleInt::MAX, DoubleInt::from(0), DoubleInt::MIN];
+    vals.sort();
+    assert_eq!(vals, [DoubleInt::MIN, DoubleInt::from(0), DoubleInt::MAX]);
+}
+
+#[test]
+fn i64_as_string() {
+    use double_int::I64AsString;
+
+    // 2^63 is the closest f64 to i64::MAX but still larger, so comparison must not round
+    let max = I64AsString::new(i64::MAX);
+    assert_eq!(
+        max.partial_cmp(&9_223_372_036_854_775_807.0_f64),
+        Some(Ordering::Less)
+    );
+    assert_eq!(max.partial_cmp(&9.2e18_f64), Some(Ordering::Greater));
+    assert_eq!(
+        I64AsString::new(i64::MIN).partial_cmp(&-9_223_372_036_854_775_808.0_f64),
+        Some(Ordering::Equal),
+    );
+    assert_eq!(max.partial_cmp(&f64::NAN), None);
+
+    assert!(max > DoubleInt::MAX);
+    assert!(DoubleInt::MIN > I64AsString::new(i64::MIN));
+    assert!(max < u64::MAX);
+    assert!(max < u128::MAX);
+    assert_eq!(I64AsString::new(-1), -1_i8);
+}
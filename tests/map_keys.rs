@@ -0,0 +1,208 @@
+use std::collections::{BTreeMap, HashMap};
+
+use double_int::DoubleInt;
+use serde::{Deserialize, Serialize};
+
+fn btree_map() -> BTreeMap<DoubleInt, String> {
+    BTreeMap::from([
+        (DoubleInt::MIN, "min".to_owned()),
+        (DoubleInt::from(-1), "minus one".to_owned()),
+        (DoubleInt::from(42), "answer".to_owned()),
+        (DoubleInt::MAX, "max".to_owned()),
+    ])
+}
+
+fn hash_map() -> HashMap<DoubleInt, String> {
+    btree_map().into_iter().collect()
+}
+
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+struct StringKeyed {
+    #[serde(with = "double_int::string_keys")]
+    btree: BTreeMap<DoubleInt, String>,
+
+    #[serde(with = "double_int::string_keys")]
+    hash: HashMap<DoubleInt, String>,
+}
+
+fn string_keyed() -> StringKeyed {
+    StringKeyed {
+        btree: btree_map(),
+        hash: hash_map(),
+    }
+}
+
+#[test]
+fn json() {
+    let json = serde_json::to_string(&btree_map()).unwrap();
+    assert_eq!(
+        json,
+        r#"{"-9007199254740991":"min","-1":"minus one","42":"answer","9007199254740991":"max"}"#,
+    );
+    assert_eq!(
+        serde_json::from_str::<BTreeMap<DoubleInt, String>>(&json).unwrap(),
+        btree_map(),
+    );
+
+    let json = serde_json::to_string(&hash_map()).unwrap();
+    assert_eq!(
+        serde_json::from_str::<HashMap<DoubleInt, String>>(&json).unwrap(),
+        hash_map(),
+    );
+
+    let json = serde_json::to_string(&string_keyed()).unwrap();
+    assert_eq!(
+        serde_json::from_str::<StringKeyed>(&json).unwrap(),
+        string_keyed(),
+    );
+
+    serde_json::from_str::<BTreeMap<DoubleInt, String>>(r#"{"9007199254740992":"foo"}"#)
+        .unwrap_err();
+    serde_json::from_str::<BTreeMap<DoubleInt, String>>(r#"{"4.2":"foo"}"#).unwrap_err();
+    serde_json::from_str::<BTreeMap<DoubleInt, String>>(r#"{"foo":"foo"}"#).unwrap_err();
+}
+
+#[test]
+fn toml() {
+    let toml = toml::to_string(&string_keyed()).unwrap();
+    assert_eq!(
+        toml::from_str::<StringKeyed>(&toml).unwrap(),
+        string_keyed()
+    );
+
+    let map = toml::from_str::<StringKeyed>(
+        r#"
+            [btree]
+            -9007199254740991 = "min"
+            -1 = "minus one"
+            42 = "answer"
+            "9007199254740991" = "max"
+
+            [hash]
+        "#,
+    )
+    .unwrap();
+    assert_eq!(map.btree, btree_map());
+
+    let map = toml::from_str::<BTreeMap<DoubleInt, String>>(
+        r#"
+            -9007199254740991 = "min"
+            -1 = "minus one"
+            42 = "answer"
+            "9007199254740991" = "max"
+        "#,
+    )
+    .unwrap();
+    assert_eq!(map, btree_map());
+
+    toml::from_str::<BTreeMap<DoubleInt, String>>(r#"9007199254740992 = "foo""#).unwrap_err();
+    toml::from_str::<BTreeMap<DoubleInt, String>>(r#"foo = "foo""#).unwrap_err();
+
+    toml::from_str::<StringKeyed>("[btree]\n9007199254740992 = \"foo\"\n[hash]\n").unwrap_err();
+    toml::from_str::<StringKeyed>("[btree]\nfoo = \"foo\"\n[hash]\n").unwrap_err();
+}
+
+#[test]
+fn yaml() {
+    let yaml = serde_yaml::to_string(&btree_map()).unwrap();
+    assert_eq!(
+        serde_yaml::from_str::<BTreeMap<DoubleInt, String>>(&yaml).unwrap(),
+        btree_map(),
+    );
+
+    let yaml = serde_yaml::to_string(&hash_map()).unwrap();
+    assert_eq!(
+        serde_yaml::from_str::<HashMap<DoubleInt, String>>(&yaml).unwrap(),
+        hash_map(),
+    );
+
+    let yaml = serde_yaml::to_string(&string_keyed()).unwrap();
+    assert_eq!(
+        serde_yaml::from_str::<StringKeyed>(&yaml).unwrap(),
+        string_keyed(),
+    );
+
+    let yaml = "btree: { '-1': minus one }\nhash: { 42: answer }\n";
+    let map = serde_yaml::from_str::<StringKeyed>(yaml).unwrap();
+    assert_eq!(map.btree[&-1], "minus one");
+    assert_eq!(map.hash[&42], "answer");
+
+    serde_yaml::from_str::<BTreeMap<DoubleInt, String>>("9007199254740992: foo").unwrap_err();
+    serde_yaml::from_str::<StringKeyed>("btree: { '9007199254740992': foo }\nhash: {}\n")
+        .unwrap_err();
+}
+
+#[test]
+fn urlencoded() {
+    let form = serde_urlencoded::to_string(btree_map()).unwrap();
+    assert_eq!(
+        form,
+        "-9007199254740991=min&-1=minus+one&42=answer&9007199254740991=max",
+    );
+    assert_eq!(
+        serde_urlencoded::from_str::<BTreeMap<DoubleInt, String>>(&form).unwrap(),
+        btree_map(),
+    );
+
+    let form = serde_urlencoded::to_string(hash_map()).unwrap();
+    assert_eq!(
+        serde_urlencoded::from_str::<HashMap<DoubleInt, String>>(&form).unwrap(),
+        hash_map(),
+    );
+
+    serde_urlencoded::from_str::<BTreeMap<DoubleInt, String>>("9007199254740992=foo").unwrap_err();
+    serde_urlencoded::from_str::<BTreeMap<DoubleInt, String>>("foo=foo").unwrap_err();
+}
+
+#[test]
+fn double_uint() {
+    use double_int::DoubleUint;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct UintKeyed {
+        #[serde(with = "double_int::string_keys")]
+        btree: BTreeMap<DoubleUint, String>,
+    }
+
+    let map = UintKeyed {
+        btree: BTreeMap::from([
+            (DoubleUint::MIN, "min".to_owned()),
+            (DoubleUint::MAX, "max".to_owned()),
+        ]),
+    };
+
+    let toml = toml::to_string(&map).unwrap();
+    assert_eq!(toml::from_str::<UintKeyed>(&toml).unwrap(), map);
+
+    let json = serde_json::to_string(&map).unwrap();
+    assert_eq!(serde_json::from_str::<UintKeyed>(&json).unwrap(), map);
+
+    toml::from_str::<UintKeyed>("[btree]\n-1 = \"minus one\"\n").unwrap_err();
+    serde_json::from_str::<UintKeyed>(r#"{"btree":{"9007199254740992":"foo"}}"#).unwrap_err();
+}
+
+#[test]
+fn single_int() {
+    use double_int::SingleInt;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct SingleKeyed {
+        #[serde(with = "double_int::string_keys")]
+        btree: BTreeMap<SingleInt, String>,
+    }
+
+    let map = SingleKeyed {
+        btree: BTreeMap::from([
+            (SingleInt::MIN, "min".to_owned()),
+            (SingleInt::MAX, "max".to_owned()),
+        ]),
+    };
+
+    let toml = toml::to_string(&map).unwrap();
+    assert_eq!(toml::from_str::<SingleKeyed>(&toml).unwrap(), map);
+
+    let json = serde_json::to_string(&map).unwrap();
+    assert_eq!(serde_json::from_str::<SingleKeyed>(&json).unwrap(), map);
+
+    serde_json::from_str::<SingleKeyed>(r#"{"btree":{"16777216":"foo"}}"#).unwrap_err();
+}